@@ -0,0 +1,37 @@
+// `distance_small` against `distance` on word-sized pairs (16 chars or
+// fewer), where avoiding the heap should be a clear win. Run with
+// `cargo bench --features testutil --bench distance_small`.
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use rstring::{SMALL_CAPACITY, distance, distance_small, random_pair};
+
+fn short_pairs() -> Vec<(String, String)> {
+    (0..256u64)
+        .map(|seed| random_pair(seed, 4 + (seed % 10) as usize, 0.3))
+        .filter(|(a, b)| a.len().max(b.len()) <= SMALL_CAPACITY)
+        .collect()
+}
+
+fn bench_short(c: &mut Criterion) {
+    let pairs = short_pairs();
+    let mut group = c.benchmark_group("short_pairs");
+    group.bench_function("distance", |bench| {
+        bench.iter(|| {
+            for (a, b) in &pairs {
+                black_box(distance(black_box(a), black_box(b)));
+            }
+        })
+    });
+    group.bench_function("distance_small", |bench| {
+        bench.iter(|| {
+            for (a, b) in &pairs {
+                black_box(distance_small(black_box(a), black_box(b)));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_short);
+criterion_main!(benches);
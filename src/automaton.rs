@@ -0,0 +1,81 @@
+use alloc::vec::Vec;
+
+// One DP row of a `LevAutomaton`: entry `j` is the edit distance between the
+// characters fed so far and the first `j` characters of the pattern, capped
+// at `max_edits + 1` (saturating) so rows that differ only beyond the limit
+// compare equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct State {
+    row: Vec<usize>,
+}
+
+// Levenshtein automaton accepting every string within `max_edits` of
+// `pattern`, simulated one DP row at a time. Feed it characters with `step`
+// while walking a trie or any other char-yielding structure; once
+// `can_match` is false no continuation can come back within the limit, so
+// the whole branch can be skipped. Each step costs O(pattern length).
+#[derive(Debug, Clone)]
+pub struct LevAutomaton {
+    pattern: Vec<char>,
+    max_edits: usize,
+}
+
+impl LevAutomaton {
+    pub fn new(pattern: &str, max_edits: usize) -> Self {
+        LevAutomaton {
+            pattern: pattern.chars().collect(),
+            max_edits,
+        }
+    }
+
+    // State before any input: distances to each pattern prefix are the
+    // prefix lengths.
+    pub fn start(&self) -> State {
+        let cap = self.max_edits.saturating_add(1);
+        State {
+            row: (0..=self.pattern.len()).map(|j| j.min(cap)).collect(),
+        }
+    }
+
+    // State after feeding `c` from `state`.
+    pub fn step(&self, state: &State, c: char) -> State {
+        let cap = self.max_edits.saturating_add(1);
+        let prev = &state.row;
+        let mut row: Vec<usize> = Vec::with_capacity(prev.len());
+        row.push((prev[0] + 1).min(cap));
+        for (j, &pc) in self.pattern.iter().enumerate() {
+            let cost = if pc == c { 0 } else { 1 };
+            let deletion = prev[j + 1] + 1;
+            let insertion = row[j] + 1;
+            let substitution = prev[j] + cost;
+            row.push(deletion.min(insertion).min(substitution).min(cap));
+        }
+        State { row }
+    }
+
+    // Some(distance) if the input fed so far is within `max_edits` of the
+    // whole pattern.
+    pub fn is_match(&self, state: &State) -> Option<usize> {
+        let d = state.row[self.pattern.len()];
+        (d <= self.max_edits).then_some(d)
+    }
+
+    // False once no continuation of the input fed so far can match, i.e.
+    // every cell of the row is over the limit.
+    pub fn can_match(&self, state: &State) -> bool {
+        state.row.iter().any(|&d| d <= self.max_edits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unbounded_limit_does_not_overflow() {
+        let automaton = LevAutomaton::new("abc", usize::MAX);
+        let state = automaton.step(&automaton.start(), 'x');
+        assert_eq!(automaton.is_match(&state), Some(3));
+        assert!(automaton.can_match(&state));
+    }
+}
@@ -0,0 +1,122 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::{vec, vec::Vec};
+
+use crate::levenshtein::distance;
+
+#[derive(Debug, Clone)]
+struct Node {
+    word: String,
+    // Child node index keyed by its distance to this node's word
+    children: BTreeMap<usize, usize>,
+}
+
+// Burkhard-Keller tree over Levenshtein distance (or another metric, see
+// `with_metric`), for fuzzy dictionary lookup. Every child of a node sits at
+// a distinct distance from it, so by the triangle inequality a query within
+// `max_dist` of `word` only has to descend into children whose edge lies in
+// `d - max_dist..=d + max_dist`, where `d` is the query's distance to the
+// node. Nodes live in a flat arena.
+#[derive(Debug, Clone)]
+pub struct BkTree {
+    nodes: Vec<Node>,
+    metric: fn(&str, &str) -> usize,
+}
+
+impl Default for BkTree {
+    fn default() -> Self {
+        BkTree::with_metric(|a, b| distance(a, b))
+    }
+}
+
+impl BkTree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // An empty tree ordered by `metric` instead of `distance`, e.g.
+    // `distance_ignore_case` for case-insensitive lookup. The same metric is
+    // used for every insert and query, which is what the pruning relies on:
+    // it must be a true metric (symmetric, satisfying the triangle
+    // inequality), or queries can silently miss words. Words at distance 0
+    // from one already present (such as "Apple" after "apple" when ignoring
+    // case) are treated as duplicates.
+    pub fn with_metric(metric: fn(&str, &str) -> usize) -> Self {
+        BkTree {
+            nodes: Vec::new(),
+            metric,
+        }
+    }
+
+    // Number of distinct words in the tree.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    // Adds `word` to the tree. Words already present are ignored.
+    pub fn insert(&mut self, word: String) {
+        let next = self.nodes.len();
+        let mut idx = 0;
+        while idx < next {
+            let d = (self.metric)(&word, &self.nodes[idx].word);
+            if d == 0 {
+                return;
+            }
+            match self.nodes[idx].children.get(&d) {
+                Some(&child) => idx = child,
+                None => {
+                    self.nodes[idx].children.insert(d, next);
+                    break;
+                }
+            }
+        }
+        self.nodes.push(Node {
+            word,
+            children: BTreeMap::new(),
+        });
+    }
+
+    // All words within `max_dist` edits of `word`, with their distances,
+    // sorted by distance (ties in insertion order).
+    pub fn query(&self, word: &str, max_dist: usize) -> Vec<(&str, usize)> {
+        if self.nodes.is_empty() {
+            return Vec::new();
+        }
+
+        let mut found: Vec<(usize, &str, usize)> = Vec::new();
+        let mut pending = vec![0];
+        while let Some(idx) = pending.pop() {
+            let node = &self.nodes[idx];
+            let d = (self.metric)(word, &node.word);
+            if d <= max_dist {
+                found.push((d, &node.word, idx));
+            }
+            let lo = d.saturating_sub(max_dist);
+            let hi = d.saturating_add(max_dist);
+            pending.extend(node.children.range(lo..=hi).map(|(_, &child)| child));
+        }
+
+        found.sort_unstable_by_key(|&(d, _, idx)| (d, idx));
+        found.into_iter().map(|(d, w, _)| (w, d)).collect()
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for BkTree {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let mut tree = BkTree::new();
+        tree.extend(iter);
+        tree
+    }
+}
+
+impl<S: Into<String>> Extend<S> for BkTree {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, iter: I) {
+        for word in iter {
+            self.insert(word.into());
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+#[cfg(feature = "std")]
+use crate::damerau::damerau_distance;
+use crate::damerau::osa_distance;
+use crate::levenshtein::{collapse_whitespace, distance, partial_distance};
+
+// Edit-distance algorithm used by a `Comparator`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Algorithm {
+    // Plain Levenshtein distance (`distance`).
+    #[default]
+    Levenshtein,
+    // Unrestricted Damerau-Levenshtein (`damerau_distance`, needs `std`).
+    #[cfg(feature = "std")]
+    DamerauLevenshtein,
+    // Optimal string alignment (`osa_distance`).
+    OptimalStringAlignment,
+    // Best match of the shorter string inside the longer (`partial_distance`).
+    Partial,
+}
+
+// Composable string comparison: a preprocessing pipeline plus an algorithm.
+//
+//     let cmp = Comparator::new()
+//         .ignore_case(true)
+//         .trim_whitespace(true)
+//         .algorithm(Algorithm::OptimalStringAlignment);
+//     cmp.distance("  Hello  World", "hello wrold"); // 1
+//
+// Preprocessing runs once per input, before dispatching to the algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Comparator {
+    ignore_case: bool,
+    trim_whitespace: bool,
+    algorithm: Algorithm,
+}
+
+impl Comparator {
+    // Plain Levenshtein with no preprocessing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Lowercase both inputs before comparing.
+    pub fn ignore_case(mut self, yes: bool) -> Self {
+        self.ignore_case = yes;
+        self
+    }
+
+    // Trim both inputs and collapse inner whitespace runs to one space.
+    pub fn trim_whitespace(mut self, yes: bool) -> Self {
+        self.trim_whitespace = yes;
+        self
+    }
+
+    pub fn algorithm(mut self, algorithm: Algorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    // Applies the configured preprocessing, borrowing when there is none.
+    fn prepare<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        let mut s = Cow::Borrowed(s);
+        if self.ignore_case {
+            s = Cow::Owned(s.to_lowercase());
+        }
+        if self.trim_whitespace {
+            s = Cow::Owned(collapse_whitespace(&s).into_iter().collect::<String>());
+        }
+        s
+    }
+
+    fn raw_distance(&self, a: &str, b: &str) -> usize {
+        match self.algorithm {
+            Algorithm::Levenshtein => distance(a, b),
+            #[cfg(feature = "std")]
+            Algorithm::DamerauLevenshtein => damerau_distance(a, b),
+            Algorithm::OptimalStringAlignment => osa_distance(a, b),
+            Algorithm::Partial => partial_distance(a, b),
+        }
+    }
+
+    // Distance between the preprocessed inputs under the chosen algorithm.
+    pub fn distance(&self, a: &str, b: &str) -> usize {
+        let (a, b) = (self.prepare(a), self.prepare(b));
+        self.raw_distance(&a, &b)
+    }
+
+    // Similarity in [0.0, 1.0]: 1 - distance / length, where the length is
+    // the longer preprocessed input (the shorter one for `Algorithm::Partial`).
+    // Two empty inputs are identical (1.0).
+    pub fn similarity(&self, a: &str, b: &str) -> f64 {
+        let (a, b) = (self.prepare(a), self.prepare(b));
+        let (a_len, b_len) = (a.chars().count(), b.chars().count());
+        let len = match self.algorithm {
+            Algorithm::Partial => a_len.min(b_len),
+            _ => a_len.max(b_len),
+        };
+        if a_len == 0 && b_len == 0 {
+            1.0
+        } else if len == 0 {
+            0.0
+        } else {
+            1.0 - self.raw_distance(&a, &b) as f64 / len as f64
+        }
+    }
+}
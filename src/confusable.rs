@@ -0,0 +1,91 @@
+use alloc::vec::Vec;
+
+use crate::levenshtein::distance_slice;
+
+// Skeleton of a single character: the character it is visually confusable
+// with, following the prototypes of Unicode's confusables data (UTS #39),
+// or `c` itself. Covered:
+// - fullwidth ASCII forms (U+FF01..U+FF5E), folded to ASCII first
+// - Cyrillic and Greek letters that look like Latin ones ("а", "О", "Ρ")
+// - a few Latin lookalikes: dotless "ı", script "ɑ" and "ɡ"
+// - the ASCII look-alike groups "l"/"1"/"I"/"|" -> "l" and "0"/"O" -> "O"
+// Prototypes that span several characters (such as "rn" for "m") are not
+// handled, since the mapping is per character.
+fn skeleton(c: char) -> char {
+    let c = match c {
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        _ => c,
+    };
+    match c {
+        // Cyrillic lowercase
+        'а' => 'a',
+        'с' => 'c',
+        'ԁ' => 'd',
+        'е' => 'e',
+        'һ' => 'h',
+        'і' => 'i',
+        'ј' => 'j',
+        'ӏ' => 'l',
+        'о' => 'o',
+        'р' => 'p',
+        'ԛ' => 'q',
+        'ѕ' => 's',
+        'ԝ' => 'w',
+        'х' => 'x',
+        'у' => 'y',
+        // Cyrillic uppercase
+        'А' => 'A',
+        'В' => 'B',
+        'С' => 'C',
+        'Е' => 'E',
+        'Н' => 'H',
+        'Ј' => 'J',
+        'К' => 'K',
+        'М' => 'M',
+        'Р' => 'P',
+        'Ѕ' => 'S',
+        'Т' => 'T',
+        'Х' => 'X',
+        'У' => 'Y',
+        'І' | 'Ӏ' => 'l',
+        'О' => 'O',
+        // Greek
+        'α' => 'a',
+        'ι' => 'i',
+        'ν' => 'v',
+        'ο' => 'o',
+        'ρ' => 'p',
+        'Α' => 'A',
+        'Β' => 'B',
+        'Ε' => 'E',
+        'Η' => 'H',
+        'Κ' => 'K',
+        'Μ' => 'M',
+        'Ν' => 'N',
+        'Ρ' => 'P',
+        'Τ' => 'T',
+        'Χ' => 'X',
+        'Υ' => 'Y',
+        'Ζ' => 'Z',
+        'Ι' => 'l',
+        'Ο' => 'O',
+        // Latin lookalikes
+        'ı' => 'i',
+        'ɑ' => 'a',
+        'ɡ' => 'g',
+        // ASCII groups
+        '1' | 'I' | '|' => 'l',
+        '0' => 'O',
+        _ => c,
+    }
+}
+
+// Levenshtein distance after mapping every character to its confusable
+// skeleton (see `skeleton` for the curated coverage), so lookalike spoofs
+// compare as equal: "pаypal" with a Cyrillic "а" is 0 from "paypal". Note
+// that the ASCII look-alike groups are folded too, so "paypa1" is also 0.
+pub fn confusable_distance(a: &str, b: &str) -> usize {
+    let a_chars: Vec<char> = a.chars().map(skeleton).collect();
+    let b_chars: Vec<char> = b.chars().map(skeleton).collect();
+    distance_slice(&a_chars, &b_chars)
+}
@@ -0,0 +1,149 @@
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+// Damerau-Levenshtein distance: like `distance`, but a swap of two adjacent
+// characters counts as a single edit. This is the unrestricted variant, so a
+// transposed pair may be edited again afterwards ("ca" -> "abc" is 2, where
+// the optimal string alignment variant gives 3). The last row each character
+// was seen in is kept in a `HashMap`, so any Unicode alphabet is supported;
+// this needs the `std` feature.
+#[cfg(feature = "std")]
+pub fn damerau_distance(a: impl AsRef<str>, b: impl AsRef<str>) -> usize {
+    fn inner(a: &str, b: &str) -> usize {
+        let a_chars: Vec<char> = a.chars().collect();
+        let b_chars: Vec<char> = b.chars().collect();
+
+        let n = a_chars.len();
+        let m = b_chars.len();
+
+        if n == 0 {
+            return m;
+        }
+        if m == 0 {
+            return n;
+        }
+
+        // Full (n + 2) x (m + 2) matrix, with an extra leading row and column
+        // holding an "infinite" sentinel so transpositions never underflow.
+        let inf = n + m;
+        let width = m + 2;
+        let mut d: Vec<usize> = vec![0; (n + 2) * width];
+
+        d[0] = inf;
+        for i in 0..=n {
+            d[(i + 1) * width] = inf;
+            d[(i + 1) * width + 1] = i;
+        }
+        for j in 0..=m {
+            d[j + 1] = inf;
+            d[width + j + 1] = j;
+        }
+
+        let mut last_row: HashMap<char, usize> = HashMap::new();
+
+        for i in 1..=n {
+            let ac = a_chars[i - 1];
+            let mut last_match_col = 0;
+            for j in 1..=m {
+                let bc = b_chars[j - 1];
+                let i1 = last_row.get(&bc).copied().unwrap_or(0);
+                let j1 = last_match_col;
+                let cost = if ac == bc {
+                    last_match_col = j;
+                    0
+                } else {
+                    1
+                };
+                let substitution = d[i * width + j] + cost;
+                let insertion = d[(i + 1) * width + j] + 1;
+                let deletion = d[i * width + j + 1] + 1;
+                let transposition = d[i1 * width + j1] + (i - i1 - 1) + 1 + (j - j1 - 1);
+                d[(i + 1) * width + j + 1] =
+                    substitution.min(insertion).min(deletion).min(transposition);
+            }
+            last_row.insert(ac, i);
+        }
+
+        d[(n + 1) * width + m + 1]
+    }
+    inner(a.as_ref(), b.as_ref())
+}
+
+// Optimal string alignment distance (restricted Damerau-Levenshtein). Adjacent
+// transpositions cost one edit, but no substring may be edited more than once,
+// so "ca" -> "abc" is 3 here versus 2 for `damerau_distance` (which may insert
+// between the swapped characters). Cheaper than the full variant: it keeps the
+// two-row DP of `distance` plus a third row to reach back to `i - 2`.
+pub fn osa_distance(a: impl AsRef<str>, b: impl AsRef<str>) -> usize {
+    fn inner(a: &str, b: &str) -> usize {
+        let a_chars: Vec<char> = a.chars().collect();
+        let b_chars: Vec<char> = b.chars().collect();
+
+        let n = a_chars.len();
+        let m = b_chars.len();
+
+        if n == 0 {
+            return m;
+        }
+        if m == 0 {
+            return n;
+        }
+
+        let mut prev2: Vec<usize> = vec![0; m + 1];
+        let mut prev: Vec<usize> = (0..=m).collect();
+        let mut curr: Vec<usize> = vec![0; m + 1];
+
+        for (i, &ac) in a_chars.iter().enumerate() {
+            curr[0] = i + 1;
+            for j in 0..m {
+                let cost = if ac == b_chars[j] { 0 } else { 1 };
+                let deletion = prev[j + 1] + 1;
+                let insertion = curr[j] + 1;
+                let substitution = prev[j] + cost;
+                let mut best = deletion.min(insertion).min(substitution);
+                // Only an exact swap of the two characters ending here counts,
+                // and it builds on the cell two rows and columns back, which
+                // covers neither swapped character. Nothing can be edited
+                // between or after the swap in the same step, which is what
+                // keeps "ca" -> "abc" at 3 rather than Damerau's 2.
+                if i > 0 && j > 0 && ac == b_chars[j - 1] && a_chars[i - 1] == b_chars[j] {
+                    best = best.min(prev2[j - 1] + 1);
+                }
+                curr[j + 1] = best;
+            }
+            // Rotate rows: prev2 <- prev, prev <- curr, and reuse the old prev2
+            core::mem::swap(&mut prev2, &mut prev);
+            core::mem::swap(&mut prev, &mut curr);
+        }
+
+        prev[m]
+    }
+    inner(a.as_ref(), b.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn osa_forbids_editing_inside_a_swap() {
+        assert_eq!(osa_distance("CA", "ABC"), 3);
+        #[cfg(feature = "std")]
+        assert_eq!(damerau_distance("CA", "ABC"), 2);
+    }
+
+    #[test]
+    fn osa_counts_an_adjacent_swap_once() {
+        assert_eq!(osa_distance("xabcy", "xbacy"), 1);
+        assert_eq!(osa_distance("ab", "ba"), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn damerau_counts_transpositions_once() {
+        assert_eq!(damerau_distance("ca", "abc"), 2);
+        assert_eq!(damerau_distance("ab", "ba"), 1);
+        assert_eq!(osa_distance("ca", "abc"), 3);
+    }
+}
@@ -0,0 +1,55 @@
+// C ABI for calling rstring from C/C++ (requires the `ffi` feature). Build a
+// shared library with:
+//
+//     cargo rustc --lib --release --features ffi --crate-type cdylib
+//
+// Error contract: every function takes NUL-terminated UTF-8 strings. If
+// either pointer is null or the bytes are not valid UTF-8, the call fails
+// and returns a sentinel instead of a score: `usize::MAX` (SIZE_MAX) for
+// integer results and -1.0 for floating-point results.
+
+use core::ffi::{CStr, c_char, c_double};
+
+use crate::levenshtein::{distance, normalized_distance};
+
+// Borrows a C string as `&str`, or None if null or not valid UTF-8.
+unsafe fn to_str<'a>(ptr: *const c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        return None;
+    }
+    // SAFETY: non-null, and the caller guarantees NUL termination
+    unsafe { CStr::from_ptr(ptr) }.to_str().ok()
+}
+
+/// Levenshtein distance between two C strings, or `SIZE_MAX` if either is
+/// null or not valid UTF-8.
+///
+/// # Safety
+///
+/// Each non-null pointer must point to a NUL-terminated string that stays
+/// valid and unmodified for the duration of the call.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rstring_distance(a: *const c_char, b: *const c_char) -> usize {
+    match unsafe { (to_str(a), to_str(b)) } {
+        (Some(a), Some(b)) => distance(a, b),
+        _ => usize::MAX,
+    }
+}
+
+/// Normalized Levenshtein distance in [0.0, 1.0] between two C strings, or
+/// -1.0 if either is null or not valid UTF-8.
+///
+/// # Safety
+///
+/// Each non-null pointer must point to a NUL-terminated string that stays
+/// valid and unmodified for the duration of the call.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rstring_normalized_distance(
+    a: *const c_char,
+    b: *const c_char,
+) -> c_double {
+    match unsafe { (to_str(a), to_str(b)) } {
+        (Some(a), Some(b)) => normalized_distance(a, b),
+        _ => -1.0,
+    }
+}
@@ -0,0 +1,49 @@
+// Hamming distance: the number of positions at which two strings of equal
+// character length differ. Returns None if the lengths differ.
+pub fn hamming(a: &str, b: &str) -> Option<usize> {
+    let mut a_iter = a.chars();
+    let mut b_iter = b.chars();
+    let mut count = 0;
+
+    loop {
+        match (a_iter.next(), b_iter.next()) {
+            (Some(ac), Some(bc)) => {
+                if ac != bc {
+                    count += 1;
+                }
+            }
+            (None, None) => return Some(count),
+            _ => return None,
+        }
+    }
+}
+
+// Hamming distance normalized to [0.0, 1.0] by the shared length.
+// Two empty strings are identical (0.0); mismatched lengths give None.
+pub fn normalized_hamming(a: &str, b: &str) -> Option<f64> {
+    let d = hamming(a, b)? as f64;
+    let len = a.chars().count() as f64;
+    if len == 0.0 {
+        Some(0.0)
+    } else {
+        Some(d / len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hamming_counts_differing_positions() {
+        assert_eq!(hamming("karolin", "kathrin"), Some(3));
+        assert_eq!(normalized_hamming("karolin", "kathrin"), Some(3.0 / 7.0));
+        assert_eq!(hamming("", ""), Some(0));
+    }
+
+    #[test]
+    fn hamming_rejects_length_mismatch() {
+        assert_eq!(hamming("abc", "ab"), None);
+        assert_eq!(normalized_hamming("a", ""), None);
+    }
+}
@@ -0,0 +1,127 @@
+use alloc::{vec, vec::Vec};
+
+// Jaro-Winkler prefix scaling factor and the maximum prefix length rewarded.
+const WINKLER_PREFIX_SCALE: f64 = 0.1;
+const WINKLER_MAX_PREFIX: usize = 4;
+
+// Jaro similarity in [0.0, 1.0], 1.0 meaning identical. Characters match if
+// they are equal and no further apart than half the longer length (minus
+// one); half the number of matched characters appearing out of order are
+// counted as transpositions.
+pub fn jaro(a: impl AsRef<str>, b: impl AsRef<str>) -> f64 {
+    fn inner(a: &str, b: &str) -> f64 {
+        let a_chars: Vec<char> = a.chars().collect();
+        let b_chars: Vec<char> = b.chars().collect();
+
+        let n = a_chars.len();
+        let m = b_chars.len();
+
+        if n == 0 && m == 0 {
+            return 1.0;
+        }
+        if n == 0 || m == 0 {
+            return 0.0;
+        }
+
+        let window = (n.max(m) / 2).saturating_sub(1);
+        let mut a_matched = vec![false; n];
+        let mut b_matched = vec![false; m];
+        let mut matches = 0;
+
+        for (i, &ac) in a_chars.iter().enumerate() {
+            let lo = i.saturating_sub(window);
+            let hi = (i + window + 1).min(m);
+            for j in lo..hi {
+                if !b_matched[j] && ac == b_chars[j] {
+                    a_matched[i] = true;
+                    b_matched[j] = true;
+                    matches += 1;
+                    break;
+                }
+            }
+        }
+
+        if matches == 0 {
+            return 0.0;
+        }
+
+        // Walk matched characters of both strings in order, counting mismatches
+        let mut half_transpositions = 0;
+        let mut k = 0;
+        for (i, &ac) in a_chars.iter().enumerate() {
+            if !a_matched[i] {
+                continue;
+            }
+            while !b_matched[k] {
+                k += 1;
+            }
+            if ac != b_chars[k] {
+                half_transpositions += 1;
+            }
+            k += 1;
+        }
+
+        let matches = matches as f64;
+        let transpositions = half_transpositions as f64 / 2.0;
+        (matches / n as f64 + matches / m as f64 + (matches - transpositions) / matches) / 3.0
+    }
+    inner(a.as_ref(), b.as_ref())
+}
+
+// Jaro-Winkler similarity in [0.0, 1.0]: Jaro similarity boosted for a
+// common prefix of up to 4 characters, with a scaling factor of 0.1.
+pub fn jaro_winkler(a: impl AsRef<str>, b: impl AsRef<str>) -> f64 {
+    fn inner(a: &str, b: &str) -> f64 {
+        jaro_winkler_config(a, b, WINKLER_PREFIX_SCALE, WINKLER_MAX_PREFIX)
+    }
+    inner(a.as_ref(), b.as_ref())
+}
+
+// Jaro-Winkler similarity with a custom prefix bonus: a common prefix of up
+// to `max_prefix` characters raises the Jaro score by `prefix_scale` of the
+// remaining gap per character. The result stays in [0.0, 1.0] only while
+// `prefix_scale * max_prefix <= 1.0`, so `prefix_scale` is clamped to
+// `0.0..=1.0 / max_prefix` (at most 0.25 for a 4-character prefix) rather
+// than rejected. A NaN `prefix_scale` counts as 0.0, giving plain `jaro`.
+pub fn jaro_winkler_config(a: &str, b: &str, prefix_scale: f64, max_prefix: usize) -> f64 {
+    let j = jaro(a, b);
+    let prefix = a
+        .chars()
+        .zip(b.chars())
+        .take(max_prefix)
+        .take_while(|(ac, bc)| ac == bc)
+        .count();
+    if prefix == 0 || prefix_scale.is_nan() {
+        return j;
+    }
+    let scale = prefix_scale.clamp(0.0, 1.0 / max_prefix as f64);
+    j + prefix as f64 * scale * (1.0 - j)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn martha_marhta() {
+        assert!((jaro("MARTHA", "MARHTA") - 0.944).abs() < 1e-3);
+        assert!((jaro_winkler("MARTHA", "MARHTA") - 0.961).abs() < 1e-3);
+    }
+
+    #[test]
+    fn jaro_bounds() {
+        assert_eq!(jaro("", ""), 1.0);
+        assert_eq!(jaro("abc", ""), 0.0);
+        assert_eq!(jaro("abc", "xyz"), 0.0);
+        assert_eq!(jaro_winkler("same", "same"), 1.0);
+    }
+
+    #[test]
+    fn nan_prefix_scale_is_plain_jaro() {
+        assert_eq!(jaro_winkler_config("ab", "ab", f64::NAN, 4), 1.0);
+        assert_eq!(
+            jaro_winkler_config("MARTHA", "MARHTA", f64::NAN, 4),
+            jaro("MARTHA", "MARHTA")
+        );
+    }
+}
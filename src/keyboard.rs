@@ -0,0 +1,39 @@
+use alloc::vec::Vec;
+
+use crate::levenshtein::float_weighted_distance;
+
+// QWERTY letter rows with their horizontal stagger, in key widths.
+const QWERTY_ROWS: [(&str, f64); 3] = [("qwertyuiop", 0.0), ("asdfghjkl", 0.25), ("zxcvbnm", 0.75)];
+
+// (row, column) of a letter on the QWERTY layout, ignoring ASCII case.
+fn key_position(c: char) -> Option<(f64, f64)> {
+    let c = c.to_ascii_lowercase();
+    QWERTY_ROWS
+        .iter()
+        .enumerate()
+        .find_map(|(row, (keys, offset))| keys.find(c).map(|col| (row as f64, col as f64 + offset)))
+}
+
+// Cost of substituting one character for another: 0.5 for neighbouring keys
+// rising to 1.0 for keys three or more apart (Chebyshev distance on the
+// staggered grid). Characters off the letter rows always cost 1.0.
+fn key_cost(x: char, y: char) -> f64 {
+    match (key_position(x), key_position(y)) {
+        (Some((xr, xc)), Some((yr, yc))) => {
+            let dist = (xr - yr).abs().max((xc - yc).abs());
+            (0.25 + 0.25 * dist).clamp(0.5, 1.0)
+        }
+        _ => 1.0,
+    }
+}
+
+// Keyboard-aware edit distance for typo correction: insertions and deletions
+// cost 1.0, while a substitution costs between 0.5 (adjacent QWERTY keys,
+// e.g. 'a' for 's') and 1.0 (far apart, e.g. 's' for 'p'). Letters compare
+// case-insensitively for key placement only; anything that is not an ASCII
+// letter falls back to 1.0 for any mismatch.
+pub fn qwerty_distance(a: &str, b: &str) -> f64 {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    float_weighted_distance(&a_chars, &b_chars, 1.0, 1.0, key_cost)
+}
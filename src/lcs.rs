@@ -0,0 +1,167 @@
+use alloc::string::String;
+use alloc::{vec, vec::Vec};
+
+// Length of the longest common subsequence of `a` and `b`, in characters.
+// Uses a two-row DP, so memory is linear in the length of `b`.
+pub fn lcs_length(a: &str, b: &str) -> usize {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+
+    let m = b_chars.len();
+    if a_chars.is_empty() || m == 0 {
+        return 0;
+    }
+
+    let mut prev: Vec<usize> = vec![0; m + 1];
+    let mut curr: Vec<usize> = vec![0; m + 1];
+
+    for &ac in &a_chars {
+        for j in 0..m {
+            curr[j + 1] = if ac == b_chars[j] {
+                prev[j] + 1
+            } else {
+                prev[j + 1].max(curr[j])
+            };
+        }
+        core::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[m]
+}
+
+// LCS similarity ratio in [0.0, 1.0]: 2 * lcs / (len_a + len_b), the basis
+// of difflib-style ratios. Two empty strings are identical (1.0).
+pub fn lcs_ratio(a: &str, b: &str) -> f64 {
+    let total = a.chars().count() + b.chars().count();
+    if total == 0 {
+        1.0
+    } else {
+        2.0 * lcs_length(a, b) as f64 / total as f64
+    }
+}
+
+// The longest common subsequence itself. Keeps the full DP matrix in order
+// to backtrace; when several subsequences are equally long, the walk back
+// prefers skipping a character of `a` over one of `b`.
+pub fn lcs_sequence(a: &str, b: &str) -> String {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+
+    let n = a_chars.len();
+    let m = b_chars.len();
+    let width = m + 1;
+
+    let mut d: Vec<usize> = vec![0; (n + 1) * width];
+    for i in 1..=n {
+        let ac = a_chars[i - 1];
+        for j in 1..=m {
+            d[i * width + j] = if ac == b_chars[j - 1] {
+                d[(i - 1) * width + j - 1] + 1
+            } else {
+                d[(i - 1) * width + j].max(d[i * width + j - 1])
+            };
+        }
+    }
+
+    let mut seq: Vec<char> = Vec::with_capacity(d[n * width + m]);
+    let (mut i, mut j) = (n, m);
+    while i > 0 && j > 0 {
+        if a_chars[i - 1] == b_chars[j - 1] {
+            seq.push(a_chars[i - 1]);
+            i -= 1;
+            j -= 1;
+        } else if d[(i - 1) * width + j] >= d[i * width + j - 1] {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+
+    seq.iter().rev().collect()
+}
+
+// Indel distance: the number of insertions and deletions (no substitutions)
+// turning `a` into `b`. Every character outside a longest common subsequence
+// must be deleted from one side or inserted from the other, so this is
+// len_a + len_b - 2 * lcs_length, the same as a weighted Levenshtein with
+// substitution cost 2.
+pub fn indel_distance(a: &str, b: &str) -> usize {
+    a.chars().count() + b.chars().count() - 2 * lcs_length(a, b)
+}
+
+// Indel distance divided by len_a + len_b, in [0.0, 1.0]. Two empty strings
+// are 0.0. For non-empty input this equals `1.0 - lcs_ratio(a, b)`.
+pub fn normalized_indel_distance(a: &str, b: &str) -> f64 {
+    let total = a.chars().count() + b.chars().count();
+    if total == 0 {
+        0.0
+    } else {
+        indel_distance(a, b) as f64 / total as f64
+    }
+}
+
+// Longest common substring of `a` and `b`, as (start in a, start in b, len).
+// Ties go to the block that starts earliest in `a`, then earliest in `b`, the
+// same choice as difflib's `find_longest_match` without junk heuristics.
+pub(crate) fn longest_block(a: &[char], b: &[char]) -> (usize, usize, usize) {
+    let m = b.len();
+    let mut best = (0, 0, 0);
+    let mut prev: Vec<usize> = vec![0; m + 1];
+    let mut curr: Vec<usize> = vec![0; m + 1];
+
+    for (i, &ac) in a.iter().enumerate() {
+        for j in 0..m {
+            curr[j + 1] = if ac == b[j] { prev[j] + 1 } else { 0 };
+            let k = curr[j + 1];
+            if k > best.2 {
+                best = (i + 1 - k, j + 1 - k, k);
+            }
+        }
+        core::mem::swap(&mut prev, &mut curr);
+    }
+
+    best
+}
+
+// Length in characters of the longest contiguous run shared by `a` and `b`
+// (unlike `lcs_length`, which allows gaps). Uses the O(n * m) two-row DP.
+pub fn longest_common_substring(a: &str, b: &str) -> usize {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    longest_block(&a_chars, &b_chars).2
+}
+
+// The longest contiguous run shared by `a` and `b`. Among runs of equal
+// length the one starting earliest in `a` wins; empty if nothing is shared.
+pub fn longest_common_substring_str(a: &str, b: &str) -> String {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    let (start, _, len) = longest_block(&a_chars, &b_chars);
+    a_chars[start..start + len].iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levenshtein::{Weights, weighted_distance};
+    use crate::testutil::random_corpus;
+
+    #[test]
+    fn indel_matches_substitution_cost_two() {
+        let w = Weights {
+            insert: 1,
+            delete: 1,
+            substitute: 2,
+        };
+        for (a, b) in random_corpus() {
+            assert_eq!(
+                indel_distance(&a, &b),
+                weighted_distance(&a, &b, &w),
+                "{a:?} {b:?}"
+            );
+        }
+        assert_eq!(indel_distance("abc", "abd"), 2);
+        assert_eq!(normalized_indel_distance("", ""), 0.0);
+        assert_eq!(normalized_indel_distance("ab", "cd"), 1.0);
+    }
+}
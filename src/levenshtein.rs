@@ -1,16 +1,339 @@
-// Simple Levenshtein distance function
-pub fn distance(a: &str, b: &str) -> usize {
+use alloc::string::String;
+use alloc::{vec, vec::Vec};
+use core::cmp::{Ordering, Reverse};
+use core::fmt;
+use core::ops::Range;
+use core::str::{self, Utf8Error};
+
+use crate::score::to_percent;
+
+// Simple Levenshtein distance function. Like the other basic scores it takes
+// any `AsRef<str>` (`&str`, `String`, `&String`, `Cow<str>`) and forwards to
+// a non-generic `inner`, so only the conversion is instantiated per type.
+pub fn distance(a: impl AsRef<str>, b: impl AsRef<str>) -> usize {
+    fn inner(a: &str, b: &str) -> usize {
+        // Identical inputs: a byte compare instead of decoding and a DP
+        if a == b {
+            return 0;
+        }
+        let a_chars: Vec<char> = a.chars().collect();
+        let b_chars: Vec<char> = b.chars().collect();
+        distance_chars(&a_chars, &b_chars)
+    }
+    inner(a.as_ref(), b.as_ref())
+}
+
+// Levenshtein distance over already-decoded characters, for callers that
+// hold `&[char]` (or need the lengths too) and would otherwise decode twice.
+pub fn distance_chars(a: &[char], b: &[char]) -> usize {
+    distance_slice(a, b)
+}
+
+// Levenshtein distance over arbitrary slices, e.g. tokens (`&[&str]`) or
+// bytes. Only a single DP row is allocated: it is updated in place, with the
+// diagonal cell of the previous row carried in a scalar. The distance is
+// symmetric, so the row is always sized to the shorter input, and any
+// shared prefix and suffix is stripped first (see `common_affix_trim`).
+pub fn distance_slice<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    distance_slice_in(a, b, &mut Vec::new())
+}
+
+// `distance_slice` with the DP row held in `row`, so `Levenshtein` can keep
+// one allocation across calls. Any previous contents are overwritten.
+fn distance_slice_in<T: PartialEq>(a: &[T], b: &[T], row: &mut Vec<usize>) -> usize {
+    let (a, b) = common_affix_trim(a, b);
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    let n = a.len();
+    let m = b.len();
+
+    if n == 0 {
+        return m;
+    }
+    if m == 0 {
+        return n;
+    }
+
+    row.clear();
+    row.extend(0..=m);
+
+    for (i, ac) in a.iter().enumerate() {
+        // `row[j]` still holds the previous row's value until it's
+        // overwritten, so `diag` only has to remember the one to its left
+        let mut diag = row[0];
+        row[0] = i + 1;
+        for j in 0..m {
+            let cost = if *ac == b[j] { 0 } else { 1 };
+            let above = row[j + 1];
+            let deletion = above + 1;
+            let insertion = row[j] + 1;
+            let substitution = diag + cost;
+            row[j + 1] = deletion.min(insertion).min(substitution);
+            diag = above;
+        }
+    }
+
+    row[m]
+}
+
+// Strips the longest common prefix and then the longest common suffix of `a`
+// and `b`. Some optimal alignment always matches these boundary elements to
+// each other, so the Levenshtein distance of the remainders is unchanged;
+// when one input is a prefix or suffix of the other, one remainder is empty
+// and the distance is just the length difference. Strings that differ only
+// in the middle (timestamps, versioned file names) shrink to a tiny DP.
+pub(crate) fn common_affix_trim<'a, T: PartialEq>(a: &'a [T], b: &'a [T]) -> (&'a [T], &'a [T]) {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+    let suffix = a
+        .iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    (&a[..a.len() - suffix], &b[..b.len() - suffix])
+}
+
+// Levenshtein distance over raw bytes, skipping UTF-8 decoding entirely.
+// Note that this counts edits per byte: a multi-byte UTF-8 character counts
+// as several units, so it is NOT equivalent to `distance` for non-ASCII
+// input. For pure-ASCII strings the two always agree. With the `simd`
+// feature, inputs of at least `SIMD_MIN_LEN` bytes take a vectorized path
+// that gives identical results.
+pub fn distance_bytes(a: &[u8], b: &[u8]) -> usize {
+    let (a, b) = common_affix_trim(a, b);
+    #[cfg(feature = "simd")]
+    if a.len().min(b.len()) >= SIMD_MIN_LEN && a.len() + b.len() <= u32::MAX as usize {
+        return distance_bytes_simd(a, b);
+    }
+    distance_slice(a, b)
+}
+
+// Character-level `distance` between two byte strings that should be UTF-8,
+// such as raw file contents: each invalid sequence is replaced with U+FFFD
+// (as `String::from_utf8_lossy` does) and then compared like any other
+// character. Valid input is borrowed, not copied.
+pub fn distance_lossy(a: &[u8], b: &[u8]) -> usize {
+    distance(String::from_utf8_lossy(a), String::from_utf8_lossy(b))
+}
+
+// Character-level `distance` between two byte strings, or the decoding error
+// of the first one (`a` checked before `b`) that is not valid UTF-8.
+pub fn distance_strict(a: &[u8], b: &[u8]) -> Result<usize, Utf8Error> {
+    Ok(distance(str::from_utf8(a)?, str::from_utf8(b)?))
+}
+
+// Largest DP size `try_distance` accepts: 2^32 cells, a few seconds of work.
+pub const DEFAULT_MAX_CELLS: u64 = 1 << 32;
+
+// Why `try_distance` refused to compute a distance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DistanceError {
+    // len_a * len_b (in characters) exceeds the cell limit.
+    TooLarge,
+}
+
+impl fmt::Display for DistanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DistanceError::TooLarge => f.write_str("inputs too large for the distance DP"),
+        }
+    }
+}
+
+impl core::error::Error for DistanceError {}
+
+// `distance` for inputs of untrusted size: Err(DistanceError::TooLarge)
+// when the DP would need more than `DEFAULT_MAX_CELLS` cells.
+pub fn try_distance(a: &str, b: &str) -> Result<usize, DistanceError> {
+    try_distance_with_limit(a, b, DEFAULT_MAX_CELLS)
+}
+
+// `try_distance` with a caller-chosen cell limit. Lengths are counted (as
+// characters, without decoding into a buffer) and their product is checked
+// before anything is allocated, so oversized input costs one linear scan.
+// The check uses the full lengths, before `distance` strips any common
+// prefix and suffix.
+pub fn try_distance_with_limit(a: &str, b: &str, max_cells: u64) -> Result<usize, DistanceError> {
+    let (n, m) = (a.chars().count() as u64, b.chars().count() as u64);
+    match n.checked_mul(m) {
+        Some(cells) if cells <= max_cells => Ok(distance(a, b)),
+        _ => Err(DistanceError::TooLarge),
+    }
+}
+
+// Lanes per vector in `distance_bytes_simd`, and the shortest input worth
+// vectorizing. Cells are `u32`, which (unlike `usize`) has a native lane-wise
+// minimum on baseline x86-64.
+#[cfg(feature = "simd")]
+const SIMD_LANES: usize = 16;
+#[cfg(feature = "simd")]
+const SIMD_MIN_LEN: usize = 2 * SIMD_LANES;
+
+// Byte Levenshtein over anti-diagonals of the DP matrix. Every cell on a
+// diagonal depends only on the two diagonals before it, so a whole run of
+// cells is computed `SIMD_LANES` at a time; reversing `b` makes the bytes
+// compared along a diagonal contiguous. Diagonals are indexed by the row
+// into `a`, taken to be the shorter input so the buffers stay small.
+#[cfg(feature = "simd")]
+fn distance_bytes_simd(a: &[u8], b: &[u8]) -> usize {
+    use core::simd::cmp::{SimdOrd, SimdPartialEq};
+    use core::simd::{Select, Simd};
+
+    let (a, b) = if a.len() > b.len() { (b, a) } else { (a, b) };
+    let n = a.len();
+    let m = b.len();
+    let reversed: Vec<u8> = b.iter().rev().copied().collect();
+
+    let one = Simd::<u32, SIMD_LANES>::splat(1);
+    let zero = Simd::<u32, SIMD_LANES>::splat(0);
+    // Diagonals k - 2, k - 1 and k: `diag[i]` is the cell (i, k - i)
+    let mut before: Vec<u32> = vec![0; n + 1];
+    let mut last: Vec<u32> = vec![0; n + 1];
+    let mut diag: Vec<u32> = vec![0; n + 1];
+
+    for k in 1..=n + m {
+        // Interior cells have 1 <= i <= n and 1 <= j = k - i <= m
+        let lo = if k > m { k - m } else { 1 };
+        let hi = n.min(k - 1);
+
+        let mut i = lo;
+        while i + SIMD_LANES <= hi + 1 {
+            let ac = Simd::<u8, SIMD_LANES>::from_slice(&a[i - 1..]);
+            let bc = Simd::<u8, SIMD_LANES>::from_slice(&reversed[m + i - k..]);
+            let cost = ac.simd_ne(bc).cast::<i32>().select(one, zero);
+            let deletion = Simd::from_slice(&last[i - 1..]) + one;
+            let insertion = Simd::from_slice(&last[i..]) + one;
+            let substitution = Simd::from_slice(&before[i - 1..]) + cost;
+            let best = deletion.simd_min(insertion).simd_min(substitution);
+            best.copy_to_slice(&mut diag[i..i + SIMD_LANES]);
+            i += SIMD_LANES;
+        }
+        for i in i..=hi {
+            let (ac, bc) = (a[i - 1], reversed[m + i - k]);
+            let cost = if ac == bc { 0 } else { 1 };
+            let deletion = last[i - 1] + 1;
+            let insertion = last[i] + 1;
+            let substitution = before[i - 1] + cost;
+            diag[i] = deletion.min(insertion).min(substitution);
+        }
+
+        // First row and first column
+        if k <= m {
+            diag[0] = k as u32;
+        }
+        if k <= n {
+            diag[k] = k as u32;
+        }
+
+        core::mem::swap(&mut before, &mut last);
+        core::mem::swap(&mut last, &mut diag);
+    }
+
+    last[n] as usize
+}
+
+// Most characters `distance_small` accepts per input.
+pub const SMALL_CAPACITY: usize = 16;
+
+// `distance` for short strings without touching the heap: both inputs are
+// decoded into fixed `[char; 16]` buffers and the DP row is a
+// `[usize; 17]`, all on the stack. Returns None if either input has more
+// than `SMALL_CAPACITY` characters, so callers can fall back to `distance`:
+//
+//     distance_small(a, b).unwrap_or_else(|| distance(a, b))
+//
+// Meant for autocomplete-style workloads dominated by short words, where
+// the two allocations `distance` makes per call cost more than the DP.
+#[inline]
+pub fn distance_small(a: &str, b: &str) -> Option<usize> {
+    let mut a_buf = ['\0'; SMALL_CAPACITY];
+    let mut b_buf = ['\0'; SMALL_CAPACITY];
+    let n = fill_small(&mut a_buf, a)?;
+    let m = fill_small(&mut b_buf, b)?;
+    let (a, b) = (&a_buf[..n], &b_buf[..m]);
+
+    let mut row = [0usize; SMALL_CAPACITY + 1];
+    for (j, cell) in row.iter_mut().enumerate().take(m + 1) {
+        *cell = j;
+    }
+    for (i, &ac) in a.iter().enumerate() {
+        let mut diag = row[0];
+        row[0] = i + 1;
+        for j in 0..m {
+            let cost = if ac == b[j] { 0 } else { 1 };
+            let above = row[j + 1];
+            let deletion = above + 1;
+            let insertion = row[j] + 1;
+            let substitution = diag + cost;
+            row[j + 1] = deletion.min(insertion).min(substitution);
+            diag = above;
+        }
+    }
+
+    Some(row[m])
+}
+
+// Decodes `s` into `buf`, returning its length, or None if it doesn't fit.
+#[inline]
+fn fill_small(buf: &mut [char; SMALL_CAPACITY], s: &str) -> Option<usize> {
+    let mut len = 0;
+    for c in s.chars() {
+        *buf.get_mut(len)? = c;
+        len += 1;
+    }
+    Some(len)
+}
+
+// Levenshtein distance between two character streams. Only `b` is buffered
+// (it becomes the DP row); `a` is consumed one character at a time and never
+// stored, so memory is O(len(b)) however long `a` is. Pass the shorter or
+// bounded input as `b`, e.g. a word as `b` and a huge lazily-read file as `a`.
+pub fn distance_iter<I, J>(a: I, b: J) -> usize
+where
+    I: IntoIterator<Item = char>,
+    J: IntoIterator<Item = char>,
+{
+    let b_chars: Vec<char> = b.into_iter().collect();
+    let m = b_chars.len();
+
+    let mut prev: Vec<usize> = (0..=m).collect();
+    let mut curr: Vec<usize> = vec![0; m + 1];
+
+    for (i, ac) in a.into_iter().enumerate() {
+        curr[0] = i + 1;
+        for j in 0..m {
+            let cost = if ac == b_chars[j] { 0 } else { 1 };
+            let deletion = prev[j + 1] + 1;
+            let insertion = curr[j] + 1;
+            let substitution = prev[j] + cost;
+            curr[j + 1] = deletion.min(insertion).min(substitution);
+        }
+        core::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[m]
+}
+
+// Bounded Levenshtein distance. Returns Some(d) if the distance is at most
+// `max`, otherwise None. A length difference larger than `max` is rejected
+// immediately, and the DP bails out as soon as every cell of a row exceeds
+// `max`, so tight thresholds are much cheaper than a full `distance`.
+pub fn distance_within(a: &str, b: &str, max: usize) -> Option<usize> {
     let a_chars: Vec<char> = a.chars().collect();
     let b_chars: Vec<char> = b.chars().collect();
 
     let n = a_chars.len();
     let m = b_chars.len();
 
+    if n.abs_diff(m) > max {
+        return None;
+    }
     if n == 0 {
-        return m;
+        return Some(m);
     }
     if m == 0 {
-        return n;
+        return Some(n);
     }
 
     let mut prev: Vec<usize> = (0..=m).collect();
@@ -18,119 +341,1704 @@ pub fn distance(a: &str, b: &str) -> usize {
 
     for (i, &ac) in a_chars.iter().enumerate() {
         curr[0] = i + 1;
+        let mut row_min = curr[0];
         for j in 0..m {
             let cost = if ac == b_chars[j] { 0 } else { 1 };
             let deletion = prev[j + 1] + 1;
             let insertion = curr[j] + 1;
             let substitution = prev[j] + cost;
             curr[j + 1] = deletion.min(insertion).min(substitution);
+            row_min = row_min.min(curr[j + 1]);
+        }
+        // Row minima never decrease, so no later row can come back under `max`
+        if row_min > max {
+            return None;
         }
-        std::mem::swap(&mut prev, &mut curr);
+        core::mem::swap(&mut prev, &mut curr);
     }
 
-    prev[m]
+    if prev[m] <= max {
+        Some(prev[m])
+    } else {
+        None
+    }
 }
 
-// Simple Levenshtein distance normalized to [0.0, 1.0].
-// 0.0 means identical, 1.0 means completely different (relative to max length).
-pub fn normalized_distance(a: &str, b: &str) -> f64 {
-    let d = distance(a, b) as f64;
-    let max = a.chars().count().max(b.chars().count()) as f64;
-    if max == 0.0 {
-        0.0
+// Banded Levenshtein distance (Ukkonen). Only cells within `k` of the main
+// diagonal are filled, so the work is O(k * min(n, m)) rather than O(n * m).
+// Any alignment costing at most `k` stays inside the band, so the result is
+// exact whenever it is Some; cells outside the band are treated as `k + 1`
+// (effectively infinity) and None is returned if the distance exceeds `k`.
+pub fn distance_banded(a: &str, b: &str, k: usize) -> Option<usize> {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    banded_chars(&a_chars, &b_chars, k)
+}
+
+// `distance_banded` over decoded characters.
+fn banded_chars(a_chars: &[char], b_chars: &[char], k: usize) -> Option<usize> {
+    let n = a_chars.len();
+    let m = b_chars.len();
+
+    if n.abs_diff(m) > k {
+        return None;
+    }
+    if n == 0 {
+        return Some(m);
+    }
+    if m == 0 {
+        return Some(n);
+    }
+    // No distance exceeds the longer length, so a wider band changes nothing;
+    // clamping also keeps `k + 1` and `i + k` from overflowing
+    let k = k.min(n.max(m));
+
+    let outside = k + 1;
+    let mut prev: Vec<usize> = (0..=m).map(|j| j.min(outside)).collect();
+    let mut curr: Vec<usize> = vec![outside; m + 1];
+
+    for i in 1..=n {
+        let lo = i.saturating_sub(k).max(1);
+        let hi = (i + k).min(m);
+
+        curr[0] = i.min(outside);
+        // The cell left of the band was last written for an older row
+        if lo > 1 {
+            curr[lo - 1] = outside;
+        }
+
+        let ac = a_chars[i - 1];
+        let mut row_min = outside;
+        for j in lo..=hi {
+            let cost = if ac == b_chars[j - 1] { 0 } else { 1 };
+            let deletion = prev[j] + 1;
+            let insertion = curr[j - 1] + 1;
+            let substitution = prev[j - 1] + cost;
+            curr[j] = deletion.min(insertion).min(substitution).min(outside);
+            row_min = row_min.min(curr[j]);
+        }
+        if row_min > k && curr[0] > k {
+            return None;
+        }
+        core::mem::swap(&mut prev, &mut curr);
+    }
+
+    if prev[m] <= k {
+        Some(prev[m])
     } else {
-        d / max
+        None
     }
 }
 
-// Simple Levenshtein similarity score: max_length - distance.
-pub fn similarity(a: &str, b: &str) -> usize {
-    let max = a.chars().count().max(b.chars().count());
-    max.saturating_sub(distance(a, b))
+// Levenshtein distance saturated at `cap`: min(distance(a, b), cap). Only a
+// band of width `cap - 1` around the diagonal is filled (after trimming any
+// common prefix and suffix), so learning that two long, unrelated strings
+// are at least `cap` apart costs O(cap * min(n, m)) instead of O(n * m).
+pub fn distance_capped(a: &str, b: &str, cap: usize) -> usize {
+    if cap == 0 {
+        return 0;
+    }
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    let (a_chars, b_chars) = common_affix_trim(&a_chars, &b_chars);
+    // A cap above the longer length never takes effect
+    let band = (cap - 1).min(a_chars.len().max(b_chars.len()));
+    banded_chars(a_chars, b_chars, band).unwrap_or(cap)
 }
 
-// Simple Levenshtein similarity normalized to [0.0, 1.0].
-pub fn normalized_similarity(a: &str, b: &str) -> f64 {
-    let max = a.chars().count().max(b.chars().count()) as f64;
-    if max == 0.0 {
-        1.0
-    } else {
-        1.0 - (distance(a, b) as f64 / max)
+// Heuristics for `smart_distance`: lengths count as close when they differ
+// by at most 1/CLOSE_RATIO of the longer, bands start at MIN_BAND and stop
+// doubling once they would cover 1/CLOSE_RATIO of the longer input, and
+// Myers is used when the shorter input has at least MYERS_MIN_LEN chars.
+const SMART_CLOSE_RATIO: usize = 4;
+const SMART_MIN_BAND: usize = 8;
+#[cfg(feature = "std")]
+const SMART_MYERS_MIN_LEN: usize = 32;
+
+// Exact Levenshtein distance, picking an algorithm from the input shape:
+// 1. the common prefix and suffix are stripped (`common_affix_trim`); if
+//    either remainder is empty the distance is the other's length
+// 2. if the remainders are close in length, `distance_banded` is tried with
+//    a band of max(length difference, 8), doubling the band while it stays
+//    under a quarter of the longer length, since similar strings of similar
+//    length typically finish in the first, narrow band
+// 3. otherwise, or if no narrow band was enough, strings whose shorter side
+//    has at least 32 chars use `distance_myers` (with the `std` feature)
+// 4. anything else runs the plain DP
+// The result always equals `distance(a, b)`; only the running time differs.
+pub fn smart_distance(a: &str, b: &str) -> usize {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+
+    let (a, b) = common_affix_trim(&a_chars, &b_chars);
+    let (n, m) = (a.len(), b.len());
+    if n == 0 || m == 0 {
+        return n.max(m);
+    }
+
+    let longer = n.max(m);
+    let diff = n.abs_diff(m);
+    if diff * SMART_CLOSE_RATIO <= longer {
+        let mut k = diff.max(SMART_MIN_BAND);
+        while k * SMART_CLOSE_RATIO <= longer {
+            if let Some(d) = banded_chars(a, b, k) {
+                return d;
+            }
+            k *= 2;
+        }
+    }
+
+    #[cfg(feature = "std")]
+    if n.min(m) >= SMART_MYERS_MIN_LEN {
+        return crate::myers::myers_chars(a, b);
     }
+    distance_slice(a, b)
 }
 
-// Computes the minimal Levenshtein distance between the smaller of the
-// two input strings and any contiguous substring of the larger string
-// with the same character length as the smaller string. This effectively
-// rolls the smaller string along the larger one and returns the best match
-// distance (0 for an exact substring match).
-pub fn partial_distance(a: &str, b: &str) -> usize {
+// Per-operation edit costs used by `weighted_distance`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Weights {
+    pub insert: usize,
+    pub delete: usize,
+    pub substitute: usize,
+}
+
+impl Default for Weights {
+    fn default() -> Self {
+        Weights {
+            insert: 1,
+            delete: 1,
+            substitute: 1,
+        }
+    }
+}
+
+// Levenshtein distance with custom operation costs. Deleting from `a`,
+// inserting into `a`, and substituting each cost their respective weight;
+// matching characters are free. With `Weights::default()` this is `distance`.
+pub fn weighted_distance(a: &str, b: &str, w: &Weights) -> usize {
     let a_chars: Vec<char> = a.chars().collect();
     let b_chars: Vec<char> = b.chars().collect();
 
-    // Identify shorter and longer by character count
-    let (short_chars, long_chars) = if a_chars.len() <= b_chars.len() {
-        (a_chars, b_chars)
-    } else {
-        (b_chars, a_chars)
-    };
+    let n = a_chars.len();
+    let m = b_chars.len();
+
+    if n == 0 {
+        return m * w.insert;
+    }
+    if m == 0 {
+        return n * w.delete;
+    }
+
+    let mut prev: Vec<usize> = (0..=m).map(|j| j * w.insert).collect();
+    let mut curr: Vec<usize> = vec![0; m + 1];
+
+    for (i, &ac) in a_chars.iter().enumerate() {
+        curr[0] = (i + 1) * w.delete;
+        for j in 0..m {
+            let cost = if ac == b_chars[j] { 0 } else { w.substitute };
+            let deletion = prev[j + 1] + w.delete;
+            let insertion = curr[j] + w.insert;
+            let substitution = prev[j] + cost;
+            curr[j + 1] = deletion.min(insertion).min(substitution);
+        }
+        core::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[m]
+}
 
-    let n = short_chars.len();
-    let m = long_chars.len();
+// `weighted_distance` as a similarity in [0.0, 1.0]:
+// 1 - distance / (max_len * max_cost), where `max_cost` is the largest of
+// the three weights. Any pair can be transformed by substituting the
+// min_len aligned characters and inserting or deleting the other
+// max_len - min_len, which is max_len operations of at most `max_cost`
+// each, so the distance never exceeds the denominator. Unlike `similarity`,
+// which subtracts a distance that heavy weights push past max_len, this
+// keeps its meaning for any `Weights`. Two empty strings, or all-zero
+// weights, give 1.0.
+pub fn weighted_similarity(a: &str, b: &str, w: &Weights) -> f64 {
+    let max_len = a.chars().count().max(b.chars().count());
+    let max_cost = w.insert.max(w.delete).max(w.substitute);
+    let bound = max_len * max_cost;
+    if bound == 0 {
+        return 1.0;
+    }
+    1.0 - weighted_distance(a, b, w) as f64 / bound as f64
+}
+
+// Levenshtein distance with a caller-supplied substitution cost. Insertions
+// and deletions cost 1; replacing `x` with `y` costs `sub_cost(x, y)`, which
+// is consulted for every aligned pair and so should return 0 when `x == y`.
+// `distance_with_cost(a, b, |x, y| (x != y) as usize)` equals `distance`.
+pub fn distance_with_cost<F: Fn(char, char) -> usize>(a: &str, b: &str, sub_cost: F) -> usize {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+
+    let n = a_chars.len();
+    let m = b_chars.len();
 
     if n == 0 {
-        return 0;
+        return m;
+    }
+    if m == 0 {
+        return n;
+    }
+
+    let mut prev: Vec<usize> = (0..=m).collect();
+    let mut curr: Vec<usize> = vec![0; m + 1];
+
+    for (i, &ac) in a_chars.iter().enumerate() {
+        curr[0] = i + 1;
+        for j in 0..m {
+            let deletion = prev[j + 1] + 1;
+            let insertion = curr[j] + 1;
+            let substitution = prev[j] + sub_cost(ac, b_chars[j]);
+            curr[j + 1] = deletion.min(insertion).min(substitution);
+        }
+        core::mem::swap(&mut prev, &mut curr);
     }
 
-    // If lengths equal, just return full distance
-    if n == m {
-        let short_str: String = short_chars.iter().collect();
-        let long_str: String = long_chars.iter().collect();
-        return distance(&short_str, &long_str);
+    prev[m]
+}
+
+// Weighted DP with fractional costs: fixed insert/delete costs and a
+// per-pair substitution cost from `sub_cost(x, y)`, consulted only when `x`
+// and `y` differ (matches are free).
+pub(crate) fn float_weighted_distance<F: Fn(char, char) -> f64>(
+    a: &[char],
+    b: &[char],
+    insert: f64,
+    delete: f64,
+    sub_cost: F,
+) -> f64 {
+    let m = b.len();
+
+    let mut prev: Vec<f64> = (0..=m).map(|j| j as f64 * insert).collect();
+    let mut curr: Vec<f64> = vec![0.0; m + 1];
+
+    for (i, &ac) in a.iter().enumerate() {
+        curr[0] = (i + 1) as f64 * delete;
+        for j in 0..m {
+            let cost = if ac == b[j] { 0.0 } else { sub_cost(ac, b[j]) };
+            let deletion = prev[j + 1] + delete;
+            let insertion = curr[j] + insert;
+            let substitution = prev[j] + cost;
+            curr[j + 1] = deletion.min(insertion).min(substitution);
+        }
+        core::mem::swap(&mut prev, &mut curr);
     }
 
-    let short_str: String = short_chars.iter().collect();
-    let mut min_dist: usize = usize::MAX;
+    prev[m]
+}
 
-    // Slide window of length `n` over the longer string
-    for start in 0..=m - n {
-        let window: String = long_chars[start..start + n].iter().collect();
-        let d = distance(&short_str, &window);
-        if d < min_dist {
-            min_dist = d;
-            if min_dist == 0 {
-                break;
-            }
+// Levenshtein distance where edits near the start cost more: an edit at
+// position `i` costs `decay^i`, so with `decay` in (0.0, 1.0] later edits
+// matter less and 1.0 is plain `distance`. The position of an edit is the
+// later of its offsets into `a` and `b` (how many characters of each were
+// consumed before it), which keeps the distance symmetric. Useful for
+// ranking identifiers that agree on their prefixes above ones that don't.
+pub fn prefix_weighted_distance(a: &str, b: &str, decay: f64) -> f64 {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+
+    let n = a_chars.len();
+    let m = b_chars.len();
+
+    // weight[i] = decay^i, built by repeated multiplication since `powi`
+    // isn't available without std
+    let mut weight: Vec<f64> = Vec::with_capacity(n.max(m) + 1);
+    let mut w = 1.0;
+    for _ in 0..=n.max(m) {
+        weight.push(w);
+        w *= decay;
+    }
+
+    let mut prev: Vec<f64> = vec![0.0; m + 1];
+    for j in 0..m {
+        prev[j + 1] = prev[j] + weight[j];
+    }
+    let mut curr: Vec<f64> = vec![0.0; m + 1];
+
+    for (i, &ac) in a_chars.iter().enumerate() {
+        curr[0] = prev[0] + weight[i];
+        for j in 0..m {
+            let cost = if ac == b_chars[j] { 0.0 } else { weight[i.max(j)] };
+            let deletion = prev[j + 1] + weight[i.max(j + 1)];
+            let insertion = curr[j] + weight[(i + 1).max(j)];
+            let substitution = prev[j] + cost;
+            curr[j + 1] = deletion.min(insertion).min(substitution);
         }
+        core::mem::swap(&mut prev, &mut curr);
     }
 
-    min_dist
+    prev[m]
 }
 
-// Levenshtein partial distance normalized to [0.0, 1.0].
+// Simple Levenshtein distance normalized to [0.0, 1.0].
 // 0.0 means identical, 1.0 means completely different (relative to max length).
-pub fn normalized_partial_distance(a: &str, b: &str) -> f64 {
-    let d = partial_distance(a, b) as f64;
-    let min = a.chars().count().min(b.chars().count()) as f64;
-    if min == 0.0 {
-        0.0
-    } else {
-        d / min
+// Two empty strings are 0.0. Never NaN, and
+// `normalized_distance(a, b) + normalized_similarity(a, b) == 1.0`; the
+// difference `1.0 - normalized_similarity(a, b)` may be off in the last bit.
+pub fn normalized_distance(a: impl AsRef<str>, b: impl AsRef<str>) -> f64 {
+    fn inner(a: &str, b: &str) -> f64 {
+        if a == b {
+            return 0.0;
+        }
+        let a_chars: Vec<char> = a.chars().collect();
+        let b_chars: Vec<char> = b.chars().collect();
+        let d = distance_chars(&a_chars, &b_chars) as f64;
+        let max = a_chars.len().max(b_chars.len()) as f64;
+        if max == 0.0 {
+            0.0
+        } else {
+            d / max
+        }
     }
+    inner(a.as_ref(), b.as_ref())
 }
 
-// Levenshtein partial similarity score: max_length - distance.
-pub fn partial_similarity(a: &str, b: &str) -> usize {
-    let min = a.chars().count().min(b.chars().count());
-    min.saturating_sub(partial_distance(a, b))
+// Levenshtein distance divided by the mean length (len_a + len_b) / 2,
+// clamped to [0.0, 1.0]. `normalized_distance` divides by the longer length,
+// so a short string against a long one can never look very different; the
+// mean penalizes length mismatch more (e.g. "a" vs "abcd" is 0.75 by max but
+// 1.0 here), which suits strings expected to be of similar size; the
+// max-based form is better when lengths legitimately vary. Two empty strings
+// are 0.0.
+pub fn normalized_distance_mean(a: &str, b: &str) -> f64 {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    let total = a_chars.len() + b_chars.len();
+    if total == 0 {
+        return 0.0;
+    }
+    let mean = total as f64 / 2.0;
+    (distance_chars(&a_chars, &b_chars) as f64 / mean).min(1.0)
 }
 
-// Levenshtein partial similarity normalized to [0.0, 1.0].
-pub fn normalized_partial_similarity(a: &str, b: &str) -> f64 {
-    let min = a.chars().count().min(b.chars().count()) as f64;
-    if min == 0.0 {
+// Levenshtein distance divided by the length of the optimal alignment, i.e.
+// the number of columns (matches, substitutions, insertions and deletions)
+// in `edit_script(a, b)`, in [0.0, 1.0]. Every alignment has at least
+// max_len columns, so this never exceeds `normalized_distance`, and the two
+// differ when the alignment uses insertions and deletions rather than
+// substitutions: "abc" vs "bcd" is 0.67 by length but 2 edits over 4
+// columns (0.5) here. Strings with nothing in common align as substitutions
+// plus the length difference, so both give 1.0. Needs the O(n * m) matrix
+// for the backtrace. Two empty strings are 0.0.
+pub fn normalized_distance_alignment(a: &str, b: &str) -> f64 {
+    let script = edit_script(a, b);
+    if script.is_empty() {
+        return 0.0;
+    }
+    let edits = script.iter().filter(|e| !matches!(e, Edit::Match(_))).count();
+    edits as f64 / script.len() as f64
+}
+
+// Simple Levenshtein similarity score: max_length - distance.
+pub fn similarity(a: impl AsRef<str>, b: impl AsRef<str>) -> usize {
+    fn inner(a: &str, b: &str) -> usize {
+        if a == b {
+            return a.chars().count();
+        }
+        let a_chars: Vec<char> = a.chars().collect();
+        let b_chars: Vec<char> = b.chars().collect();
+        let max = a_chars.len().max(b_chars.len());
+        max.saturating_sub(distance_chars(&a_chars, &b_chars))
+    }
+    inner(a.as_ref(), b.as_ref())
+}
+
+// Simple Levenshtein similarity normalized to [0.0, 1.0].
+// Two empty strings are 1.0; an empty string against a non-empty one is 0.0.
+pub fn normalized_similarity(a: impl AsRef<str>, b: impl AsRef<str>) -> f64 {
+    fn inner(a: &str, b: &str) -> f64 {
+        normalized_similarity_by(a, b, Norm::Max)
+    }
+    inner(a.as_ref(), b.as_ref())
+}
+
+// Denominator used by `normalized_similarity_by` to scale the distance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Norm {
+    // The longer length. The distance never exceeds it, so scores span the
+    // whole [0.0, 1.0] range (`normalized_similarity`).
+    #[default]
+    Max,
+    // The mean length (len_a + len_b) / 2, which penalizes a length mismatch
+    // more. The distance can exceed it, so the score is clamped at 0.0.
+    Mean,
+    // The total length len_a + len_b. Only an empty string against a
+    // non-empty one reaches 0.0; completely different strings of equal
+    // length score 0.5.
+    Sum,
+}
+
+// Levenshtein similarity `1.0 - distance / denominator` in [0.0, 1.0], with
+// the denominator chosen by `norm`. Two empty strings are 1.0 under every
+// strategy, and one empty string against a non-empty one is 0.0.
+pub fn normalized_similarity_by(a: &str, b: &str, norm: Norm) -> f64 {
+    // Identical (including both empty) is 1.0 under every norm
+    if a == b {
+        return 1.0;
+    }
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    let (a_len, b_len) = (a_chars.len(), b_chars.len());
+    let denominator = match norm {
+        Norm::Max => a_len.max(b_len) as f64,
+        Norm::Mean => (a_len + b_len) as f64 / 2.0,
+        Norm::Sum => (a_len + b_len) as f64,
+    };
+    if denominator == 0.0 {
         1.0
     } else {
-        1.0 - (partial_distance(a, b) as f64 / min)
+        (1.0 - distance_chars(&a_chars, &b_chars) as f64 / denominator).max(0.0)
+    }
+}
+
+// `normalized_similarity` as an integer percentage in 0..=100, rounded to
+// the nearest point; two empty strings are 100. Handy as an integer sort key.
+pub fn similarity_percent(a: &str, b: &str) -> u8 {
+    to_percent(normalized_similarity(a, b))
+}
+
+// The four basic Levenshtein scores for one pair of strings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Metrics {
+    pub distance: usize,
+    pub normalized_distance: f64,
+    pub similarity: usize,
+    pub normalized_similarity: f64,
+}
+
+// Computes `distance`, `normalized_distance`, `similarity` and
+// `normalized_similarity` together from a single DP pass, for callers that
+// want several of them for the same pair.
+pub fn metrics(a: &str, b: &str) -> Metrics {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+
+    let d = distance_chars(&a_chars, &b_chars);
+    let max = a_chars.len().max(b_chars.len());
+
+    let (normalized_distance, normalized_similarity) = if max == 0 {
+        (0.0, 1.0)
+    } else {
+        let nd = d as f64 / max as f64;
+        (nd, 1.0 - nd)
+    };
+
+    Metrics {
+        distance: d,
+        normalized_distance,
+        similarity: max.saturating_sub(d),
+        normalized_similarity,
+    }
+}
+
+// Case-insensitive Levenshtein distance. Both strings are lowercased with
+// `str::to_lowercase` before the DP runs, so multi-char lowercase expansions
+// (e.g. 'İ' -> "i̇") are handled. This is lowercasing, not full case folding:
+// "STRASSE" vs "straße" is still 2 since 'ß' does not expand to "ss".
+pub fn distance_ignore_case(a: &str, b: &str) -> usize {
+    distance(a.to_lowercase(), b.to_lowercase())
+}
+
+// Case-insensitive Levenshtein distance normalized to [0.0, 1.0], relative
+// to the max length of the lowercased strings.
+pub fn normalized_distance_ignore_case(a: &str, b: &str) -> f64 {
+    normalized_distance(a.to_lowercase(), b.to_lowercase())
+}
+
+// Locale-specific case-folding rules for `distance_ignore_case_locale`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    // Standard Unicode lowercasing.
+    #[default]
+    Default,
+    // Turkish/Azerbaijani dotted and dotless i: 'I' -> 'ı' and 'İ' -> 'i'.
+    Turkish,
+}
+
+// Lowercases `s` following `locale`. Only the I/İ mappings differ from the
+// standard Unicode rules; everything else goes through `str::to_lowercase`.
+fn lowercase_locale(s: &str, locale: Locale) -> String {
+    match locale {
+        Locale::Default => s.to_lowercase(),
+        Locale::Turkish => s
+            .chars()
+            .map(|c| match c {
+                'I' => 'ı',
+                'İ' => 'i',
+                c => c,
+            })
+            .collect::<String>()
+            .to_lowercase(),
+    }
+}
+
+// Case-insensitive Levenshtein distance with locale-specific folding, so
+// Turkish names compare correctly ("ISPARTA" vs "ısparta" is 0 with
+// `Locale::Turkish`). With `Locale::Default` this is `distance_ignore_case`.
+pub fn distance_ignore_case_locale(a: &str, b: &str, locale: Locale) -> usize {
+    distance(lowercase_locale(a, locale), lowercase_locale(b, locale))
+}
+
+// Characters of `s` with leading/trailing whitespace removed and every inner
+// run of whitespace (spaces, tabs, newlines, ...) collapsed to one space.
+pub(crate) fn collapse_whitespace(s: &str) -> Vec<char> {
+    let mut out: Vec<char> = Vec::with_capacity(s.len());
+    for word in s.split_whitespace() {
+        if !out.is_empty() {
+            out.push(' ');
+        }
+        out.extend(word.chars());
+    }
+    out
+}
+
+// Levenshtein distance ignoring leading, trailing and repeated whitespace:
+// both strings are trimmed and whitespace runs collapse to a single space
+// before the DP runs. A whitespace-only string compares as empty.
+pub fn distance_trim_whitespace(a: &str, b: &str) -> usize {
+    distance_slice(&collapse_whitespace(a), &collapse_whitespace(b))
+}
+
+// Computes the minimal Levenshtein distance between the smaller of the
+// two input strings and any contiguous substring of the larger string.
+// The substring may be of any length, so matches that need an insertion or
+// deletion inside the alignment are found ("abcd" in "abXcd" is 1). Returns
+// 0 for an exact substring match. When the lengths are equal neither string
+// is "the shorter", and windowing one or the other can give different
+// results, so both directions are tried and the smaller taken; this keeps
+// `partial_distance(a, b) == partial_distance(b, a)` and, since the other
+// partial functions are built on it, makes all of them symmetric too.
+pub fn partial_distance(a: impl AsRef<str>, b: impl AsRef<str>) -> usize {
+    fn inner(a: &str, b: &str) -> usize {
+        let a_chars: Vec<char> = a.chars().collect();
+        let b_chars: Vec<char> = b.chars().collect();
+
+        match a_chars.len().cmp(&b_chars.len()) {
+            Ordering::Less => substring_distance(&a_chars, &b_chars),
+            Ordering::Greater => substring_distance(&b_chars, &a_chars),
+            Ordering::Equal => {
+                let forward = substring_distance(&a_chars, &b_chars);
+                forward.min(substring_distance(&b_chars, &a_chars))
+            }
+        }
+    }
+    inner(a.as_ref(), b.as_ref())
+}
+
+// Minimal distance between `needle` and any substring of `haystack`, in a
+// single sweep over `haystack`. Each step keeps one column of the DP (one
+// cell per needle prefix); the top cell is reset to zero so an alignment may
+// start at any position, and the bottom cell is the best alignment ending at
+// the current position. Memory is O(needle) and the sweep stops as soon as
+// an exact occurrence is found.
+fn substring_distance(needle: &[char], haystack: &[char]) -> usize {
+    let n = needle.len();
+
+    let mut prev: Vec<usize> = (0..=n).collect();
+    let mut curr: Vec<usize> = vec![0; n + 1];
+    // Start from the empty window (delete the whole needle), a real distance
+    // rather than a sentinel, so the result stays meaningful for callers that
+    // normalize it even if the loop below never runs
+    let mut best = n;
+
+    for &hc in haystack {
+        if best == 0 {
+            break;
+        }
+        curr[0] = 0;
+        for i in 0..n {
+            let cost = if needle[i] == hc { 0 } else { 1 };
+            let skip_haystack = prev[i + 1] + 1;
+            let skip_needle = curr[i] + 1;
+            let substitution = prev[i] + cost;
+            curr[i + 1] = skip_haystack.min(skip_needle).min(substitution);
+        }
+        best = best.min(curr[n]);
+        core::mem::swap(&mut prev, &mut curr);
+    }
+
+    best
+}
+
+// Full (n + 1) x (m + 1) substring-alignment matrix of `needle` against
+// `haystack`, row-major. Row 0 is all zeros so an alignment may start at any
+// position of `haystack`.
+fn substring_matrix(needle: &[char], haystack: &[char]) -> Vec<usize> {
+    let n = needle.len();
+    let m = haystack.len();
+    let width = m + 1;
+
+    let mut d: Vec<usize> = vec![0; (n + 1) * width];
+    for i in 1..=n {
+        d[i * width] = i;
+        let nc = needle[i - 1];
+        for j in 1..=m {
+            let cost = if nc == haystack[j - 1] { 0 } else { 1 };
+            let skip_haystack = d[i * width + j - 1] + 1;
+            let skip_needle = d[(i - 1) * width + j] + 1;
+            let substitution = d[(i - 1) * width + j - 1] + cost;
+            d[i * width + j] = skip_haystack.min(skip_needle).min(substitution);
+        }
+    }
+    d
+}
+
+// Backtraces `substring_matrix` from the best cell of its last row and
+// returns the matched char range of `haystack` plus its distance. The
+// earliest-ending best alignment wins, and the walk back prefers diagonal
+// steps so the span is as tight as possible.
+fn best_substring_span(needle: &[char], haystack: &[char], d: &[usize]) -> (usize, usize, usize) {
+    let n = needle.len();
+    let width = haystack.len() + 1;
+
+    let last = &d[n * width..];
+    let mut end = 0;
+    for (j, &v) in last.iter().enumerate() {
+        if v < last[end] {
+            end = j;
+        }
+    }
+
+    let (mut i, mut j) = (n, end);
+    while i > 0 {
+        let here = d[i * width + j];
+        if j > 0 {
+            let (nc, hc) = (needle[i - 1], haystack[j - 1]);
+            let cost = if nc == hc { 0 } else { 1 };
+            if here == d[(i - 1) * width + j - 1] + cost {
+                i -= 1;
+                j -= 1;
+                continue;
+            }
+        }
+        if here == d[(i - 1) * width + j] + 1 {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+
+    (j, end, last[end])
+}
+
+// Byte offset of char index `idx` in `s` (`s.len()` past the end).
+fn byte_offset(s: &str, idx: usize) -> usize {
+    s.char_indices().nth(idx).map_or(s.len(), |(b, _)| b)
+}
+
+// Finds the substring of `haystack` with the smallest edit distance to
+// `needle` and returns (byte_start, byte_end, distance), so the match is
+// `&haystack[byte_start..byte_end]`. This is the substring alignment behind
+// `partial_distance`, backtraced from the best end position. Returns None
+// for an empty needle, which trivially matches everywhere.
+pub fn best_substring(needle: &str, haystack: &str) -> Option<(usize, usize, usize)> {
+    let needle_chars: Vec<char> = needle.chars().collect();
+    let haystack_chars: Vec<char> = haystack.chars().collect();
+
+    if needle_chars.is_empty() {
+        return None;
+    }
+
+    let d = substring_matrix(&needle_chars, &haystack_chars);
+    let (start, end, dist) = best_substring_span(&needle_chars, &haystack_chars, &d);
+    let (start, end) = (byte_offset(haystack, start), byte_offset(haystack, end));
+    Some((start, end, dist))
+}
+
+// Every fuzzy occurrence of `needle` in `haystack` within `max_dist` edits,
+// as (byte_start, byte_end, distance) sorted by start. The substring DP of
+// `best_substring` is swept once, with each cell also remembering where its
+// alignment started, so memory is O(needle) however long the haystack is.
+// An end position is a hit when its distance is at most `max_dist` and no
+// worse than at the neighboring ends; overlapping hits are merged into the
+// one with the smallest distance (the earliest on ties). Matches that would
+// consume none of the haystack are not reported, and an empty needle has
+// no matches.
+pub fn all_matches(needle: &str, haystack: &str, max_dist: usize) -> Vec<(usize, usize, usize)> {
+    let needle_chars: Vec<char> = needle.chars().collect();
+    let n = needle_chars.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    // Byte offset of every char index, plus the end of the string
+    let offsets: Vec<usize> = haystack
+        .char_indices()
+        .map(|(b, _)| b)
+        .chain([haystack.len()])
+        .collect();
+
+    // col[i]: (distance, start) of the best alignment of the first `i`
+    // needle characters ending at the current haystack position. On equal
+    // distances the later start wins, keeping spans tight.
+    let better = |x: (usize, usize), y: (usize, usize)| {
+        if (x.0, Reverse(x.1)) <= (y.0, Reverse(y.1)) {
+            x
+        } else {
+            y
+        }
+    };
+    let mut col: Vec<(usize, usize)> = (0..=n).map(|i| (i, 0)).collect();
+    // (distance, start) of the best alignment ending at each position
+    let mut ends: Vec<(usize, usize)> = Vec::with_capacity(offsets.len());
+    ends.push(col[n]);
+    for (j, hc) in haystack.chars().enumerate() {
+        let mut diag = col[0];
+        col[0] = (0, j + 1);
+        for i in 1..=n {
+            let cost = if needle_chars[i - 1] == hc { 0 } else { 1 };
+            let above = col[i];
+            let substitution = (diag.0 + cost, diag.1);
+            let skip_haystack = (above.0 + 1, above.1);
+            let skip_needle = (col[i - 1].0 + 1, col[i - 1].1);
+            col[i] = better(better(substitution, skip_haystack), skip_needle);
+            diag = above;
+        }
+        ends.push(col[n]);
+    }
+
+    let mut hits: Vec<(usize, usize, usize)> = Vec::new();
+    for (end, &(dist, start)) in ends.iter().enumerate() {
+        let left = end.checked_sub(1).map_or(usize::MAX, |j| ends[j].0);
+        let right = ends.get(end + 1).map_or(usize::MAX, |e| e.0);
+        if dist > max_dist || dist > left || dist > right || start == end {
+            continue;
+        }
+        // Hits arrive by end position, so only the latest ones can overlap.
+        // A strictly better hit replaces every hit it overlaps; otherwise
+        // it is dropped in favor of the earlier one.
+        let mut keep = true;
+        while let Some(&(_, last_end, last_dist)) = hits.last() {
+            if start >= last_end {
+                break;
+            }
+            if dist < last_dist {
+                hits.pop();
+            } else {
+                keep = false;
+                break;
+            }
+        }
+        if keep {
+            hits.push((start, end, dist));
+        }
+    }
+
+    hits.into_iter()
+        .map(|(start, end, dist)| (offsets[start], offsets[end], dist))
+        .collect()
+}
+
+// Levenshtein partial distance normalized to [0.0, 1.0].
+// 0.0 means identical, 1.0 means completely different (relative to min length).
+// An empty string against a non-empty one is 1.0 (even though the raw
+// `partial_distance` is 0, the empty string occurring in anything); two empty
+// strings are 0.0. Never NaN, and adding `normalized_partial_similarity(a, b)`
+// gives exactly 1.0 (though `1.0 -` it may be off in the last bit).
+pub fn normalized_partial_distance(a: impl AsRef<str>, b: impl AsRef<str>) -> f64 {
+    fn inner(a: &str, b: &str) -> f64 {
+        let (a_len, b_len) = (a.chars().count(), b.chars().count());
+        let min = a_len.min(b_len) as f64;
+        if a_len == 0 && b_len == 0 {
+            0.0
+        } else if min == 0.0 {
+            1.0
+        } else {
+            partial_distance(a, b) as f64 / min
+        }
+    }
+    inner(a.as_ref(), b.as_ref())
+}
+
+// Levenshtein partial similarity score: min_length - partial distance.
+pub fn partial_similarity(a: impl AsRef<str>, b: impl AsRef<str>) -> usize {
+    fn inner(a: &str, b: &str) -> usize {
+        let min = a.chars().count().min(b.chars().count());
+        min.saturating_sub(partial_distance(a, b))
+    }
+    inner(a.as_ref(), b.as_ref())
+}
+
+// Levenshtein partial similarity normalized to [0.0, 1.0].
+// An empty string against a non-empty one is 0.0; two empty strings are 1.0.
+pub fn normalized_partial_similarity(a: impl AsRef<str>, b: impl AsRef<str>) -> f64 {
+    fn inner(a: &str, b: &str) -> f64 {
+        let (a_len, b_len) = (a.chars().count(), b.chars().count());
+        let min = a_len.min(b_len) as f64;
+        if a_len == 0 && b_len == 0 {
+            1.0
+        } else if min == 0.0 {
+            0.0
+        } else {
+            1.0 - (partial_distance(a, b) as f64 / min)
+        }
+    }
+    inner(a.as_ref(), b.as_ref())
+}
+
+// How well `needle` appears somewhere in `haystack`, in [0.0, 1.0]:
+// 1 - d / needle_len, where `d` is the distance of the best-matching
+// substring (as in `best_substring`). Unlike the partial functions above,
+// which window whichever input is shorter and divide by the shorter length,
+// the roles here are fixed: the needle is always the one searched for and
+// sets the scale, so the score does not depend on the haystack's length,
+// and a needle longer than the haystack is penalized for the extra
+// characters rather than swapped. An empty needle scores 1.0.
+pub fn partial_similarity_ratio(needle: &str, haystack: &str) -> f64 {
+    let needle_chars: Vec<char> = needle.chars().collect();
+    let haystack_chars: Vec<char> = haystack.chars().collect();
+    if needle_chars.is_empty() {
+        return 1.0;
+    }
+    let d = substring_distance(&needle_chars, &haystack_chars);
+    (1.0 - d as f64 / needle_chars.len() as f64).clamp(0.0, 1.0)
+}
+
+// fuzzywuzzy-style partial ratio in 0..=100: the shorter string is aligned
+// against its best-matching window of the longer one (the same alignment as
+// `partial_distance`; equal lengths try both ways), and the window's
+// similarity `normalized_partial_similarity` is scaled to a percentage and
+// rounded.
+pub fn partial_ratio(a: &str, b: &str) -> u8 {
+    to_percent(normalized_partial_similarity(a, b))
+}
+
+// A single operation in an edit script turning `a` into `b`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edit {
+    Match(char),
+    Substitute(char, char),
+    Insert(char),
+    Delete(char),
+}
+
+// Computes a minimal sequence of edits turning `a` into `b`, in order. The
+// number of non-`Match` entries equals `distance(a, b)`. Unlike `distance`
+// this keeps the full (n + 1) x (m + 1) matrix so it can backtrace. When
+// several paths are optimal, the backtrace (walking from the end) prefers a
+// match/substitution, then a deletion, then an insertion, so the output is
+// stable for a given pair of inputs. This is `edit_script_with` and
+// `Preference::SubFirst`.
+pub fn edit_script(a: &str, b: &str) -> Vec<Edit> {
+    edit_script_with(a, b, Preference::SubFirst)
+}
+
+// Which step `edit_script_with` takes when several are optimal. The
+// backtrace runs from the end of both strings, so the preferred kind of
+// edit lands as late in the script as possible. With "ab" to "ba" (two
+// edits whichever way):
+//
+//     SubFirst:    Substitute('a', 'b'), Substitute('b', 'a')
+//     DeleteFirst: Insert('b'), Match('a'), Delete('b')
+//     InsertFirst: Delete('a'), Match('b'), Insert('a')
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Preference {
+    // Match or substitute, then delete, then insert (`edit_script`).
+    #[default]
+    SubFirst,
+    // Delete, then match or substitute, then insert.
+    DeleteFirst,
+    // Insert, then match or substitute, then delete.
+    InsertFirst,
+}
+
+// `edit_script` with a choice of tie-breaking among equally cheap
+// alignments. Every preference yields a minimal script, so the number of
+// non-`Match` edits is always `distance(a, b)`; only their arrangement
+// changes.
+pub fn edit_script_with(a: &str, b: &str, prefer: Preference) -> Vec<Edit> {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    edit_script_chars(&a_chars, &b_chars, prefer)
+}
+
+// `edit_script_with` over decoded characters.
+fn edit_script_chars(a_chars: &[char], b_chars: &[char], prefer: Preference) -> Vec<Edit> {
+    let n = a_chars.len();
+    let m = b_chars.len();
+    let width = m + 1;
+
+    let mut d: Vec<usize> = vec![0; (n + 1) * width];
+    for (j, cell) in d.iter_mut().take(width).enumerate() {
+        *cell = j;
+    }
+    for i in 1..=n {
+        d[i * width] = i;
+        let ac = a_chars[i - 1];
+        for j in 1..=m {
+            let cost = if ac == b_chars[j - 1] { 0 } else { 1 };
+            let deletion = d[(i - 1) * width + j] + 1;
+            let insertion = d[i * width + j - 1] + 1;
+            let substitution = d[(i - 1) * width + j - 1] + cost;
+            d[i * width + j] = deletion.min(insertion).min(substitution);
+        }
+    }
+
+    // Steps tried in order at each cell; the first one on an optimal path wins
+    let order = match prefer {
+        Preference::SubFirst => [Step::Diagonal, Step::Delete, Step::Insert],
+        Preference::DeleteFirst => [Step::Delete, Step::Diagonal, Step::Insert],
+        Preference::InsertFirst => [Step::Insert, Step::Diagonal, Step::Delete],
+    };
+
+    let mut script: Vec<Edit> = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        let here = d[i * width + j];
+        for step in order {
+            match step {
+                Step::Diagonal if i > 0 && j > 0 => {
+                    let (ac, bc) = (a_chars[i - 1], b_chars[j - 1]);
+                    let cost = if ac == bc { 0 } else { 1 };
+                    if here == d[(i - 1) * width + j - 1] + cost {
+                        script.push(if cost == 0 {
+                            Edit::Match(ac)
+                        } else {
+                            Edit::Substitute(ac, bc)
+                        });
+                        i -= 1;
+                        j -= 1;
+                        break;
+                    }
+                }
+                Step::Delete if i > 0 && here == d[(i - 1) * width + j] + 1 => {
+                    script.push(Edit::Delete(a_chars[i - 1]));
+                    i -= 1;
+                    break;
+                }
+                Step::Insert if j > 0 && here == d[i * width + j - 1] + 1 => {
+                    script.push(Edit::Insert(b_chars[j - 1]));
+                    j -= 1;
+                    break;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    script.reverse();
+    script
+}
+
+// A backtrace move in `edit_script_with`.
+#[derive(Clone, Copy)]
+enum Step {
+    Diagonal,
+    Delete,
+    Insert,
+}
+
+// Subproblems of at most this many DP cells are solved directly with the
+// full-matrix backtrace in `edit_script_linear`.
+const HIRSCHBERG_BASE_CELLS: usize = 4096;
+
+// A minimal edit script like `edit_script`, found with Hirschberg's
+// divide-and-conquer so the DP needs O(min(n, m)) working memory instead of
+// the full matrix: `a` is split in half, one forward and one backward
+// distance row locate where an optimal alignment crosses the split in `b`,
+// and each side is solved recursively. Any common prefix and suffix is
+// matched up front. The time is still O(n * m) (about twice `edit_script`),
+// so this makes long alignments fit in memory, not fast. The number of
+// non-`Match` edits is always `distance(a, b)`. Inputs small enough for the
+// full matrix (`HIRSCHBERG_BASE_CELLS`) get exactly `edit_script`'s output.
+pub fn edit_script_linear(a: &str, b: &str) -> Vec<Edit> {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+
+    // Before any trimming, which would break ties differently
+    let cells = (a_chars.len() + 1).saturating_mul(b_chars.len() + 1);
+    if cells <= HIRSCHBERG_BASE_CELLS {
+        return edit_script_chars(&a_chars, &b_chars, Preference::SubFirst);
+    }
+
+    let (a_rest, b_rest) = common_affix_trim(&a_chars, &b_chars);
+    let prefix = a_chars
+        .iter()
+        .zip(&b_chars)
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (head, tail) = (&a_chars[..prefix], &a_chars[prefix + a_rest.len()..]);
+
+    let mut script: Vec<Edit> = Vec::with_capacity(a_chars.len().max(b_chars.len()));
+    script.extend(head.iter().map(|&c| Edit::Match(c)));
+    // The DP rows follow the second input, so keep it the shorter one and
+    // mirror the edits back if the inputs had to be swapped
+    if a_rest.len() >= b_rest.len() {
+        hirschberg(a_rest, b_rest, &mut script);
+    } else {
+        let start = script.len();
+        hirschberg(b_rest, a_rest, &mut script);
+        for edit in &mut script[start..] {
+            *edit = match *edit {
+                Edit::Match(c) => Edit::Match(c),
+                Edit::Substitute(x, y) => Edit::Substitute(y, x),
+                Edit::Insert(c) => Edit::Delete(c),
+                Edit::Delete(c) => Edit::Insert(c),
+            };
+        }
+    }
+    script.extend(tail.iter().map(|&c| Edit::Match(c)));
+    script
+}
+
+// Appends an optimal script turning `a` into `b` to `out`.
+fn hirschberg(a: &[char], b: &[char], out: &mut Vec<Edit>) {
+    let (n, m) = (a.len(), b.len());
+    if n == 0 {
+        out.extend(b.iter().map(|&c| Edit::Insert(c)));
+        return;
+    }
+    if m == 0 {
+        out.extend(a.iter().map(|&c| Edit::Delete(c)));
+        return;
+    }
+    if n == 1 || m == 1 || (n + 1) * (m + 1) <= HIRSCHBERG_BASE_CELLS {
+        out.extend(edit_script_chars(a, b, Preference::SubFirst));
+        return;
+    }
+
+    let mid = n / 2;
+    // forward[j]: distance(a[..mid], b[..j]); backward[j]: distance of
+    // a[mid..] and b[m - j..], both computed on reversed sequences
+    let forward = last_row(a[..mid].iter(), b.iter());
+    let backward = last_row(a[mid..].iter().rev(), b.iter().rev());
+    let split = (0..=m)
+        .min_by_key(|&j| forward[j] + backward[m - j])
+        .unwrap_or(0);
+
+    hirschberg(&a[..mid], &b[..split], out);
+    hirschberg(&a[mid..], &b[split..], out);
+}
+
+// Last row of the Levenshtein DP of `a` against `b`: entry `j` is the
+// distance between all of `a` and the first `j` items of `b`.
+fn last_row<'a, I, J>(a: I, b: J) -> Vec<usize>
+where
+    I: Iterator<Item = &'a char>,
+    J: Iterator<Item = &'a char> + Clone,
+{
+    let mut row: Vec<usize> = (0..=b.clone().count()).collect();
+    for (i, ac) in a.enumerate() {
+        let mut diag = row[0];
+        row[0] = i + 1;
+        for (j, bc) in b.clone().enumerate() {
+            let cost = if ac == bc { 0 } else { 1 };
+            let above = row[j + 1];
+            let deletion = above + 1;
+            let insertion = row[j] + 1;
+            let substitution = diag + cost;
+            row[j + 1] = deletion.min(insertion).min(substitution);
+            diag = above;
+        }
+    }
+    row
+}
+
+// Fuzzy find for highlighting: the span of `haystack` best matching
+// `needle` as (byte_start, byte_end), found as in `best_substring`, plus the
+// `edit_script` turning `needle` into `&haystack[byte_start..byte_end]`.
+// Insertions are haystack characters missing from the needle. The number of
+// non-`Match` edits is the span's distance. An empty needle gives an empty
+// span at 0 and no edits.
+pub fn partial_edit_script(needle: &str, haystack: &str) -> (usize, usize, Vec<Edit>) {
+    let needle_chars: Vec<char> = needle.chars().collect();
+    let haystack_chars: Vec<char> = haystack.chars().collect();
+
+    let d = substring_matrix(&needle_chars, &haystack_chars);
+    let (start, end, _) = best_substring_span(&needle_chars, &haystack_chars, &d);
+    let (start, end) = (byte_offset(haystack, start), byte_offset(haystack, end));
+    (start, end, edit_script(needle, &haystack[start..end]))
+}
+
+// Renders the alignment of `edit_script(a, b)` as two strings of equal
+// character length, with `gap` where a character was inserted or deleted:
+// "kitten" / "sitting" gives "kitten-" / "sitting" with a '-' gap. Removing
+// the gaps recovers the inputs, provided `gap` doesn't occur in them.
+pub fn align(a: &str, b: &str, gap: char) -> (String, String) {
+    let script = edit_script(a, b);
+    let mut top = String::with_capacity(a.len() + script.len());
+    let mut bottom = String::with_capacity(b.len() + script.len());
+
+    for edit in script {
+        let (x, y) = match edit {
+            Edit::Match(c) => (c, c),
+            Edit::Substitute(x, y) => (x, y),
+            Edit::Insert(c) => (gap, c),
+            Edit::Delete(c) => (c, gap),
+        };
+        top.push(x);
+        bottom.push(y);
+    }
+
+    (top, bottom)
+}
+
+// The full (n + 1) x (m + 1) Levenshtein DP table for `a` (rows) and `b`
+// (columns): `matrix[i][j]` is the distance between the first `i` characters
+// of `a` and the first `j` of `b`, so the bottom-right cell is
+// `distance(a, b)`. Meant for inspection, visualization and teaching; it uses
+// O(nm) memory, so prefer `distance` anywhere performance matters.
+pub fn distance_matrix(a: &str, b: &str) -> Vec<Vec<usize>> {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+
+    let mut matrix: Vec<Vec<usize>> = Vec::with_capacity(a_chars.len() + 1);
+    matrix.push((0..=b_chars.len()).collect());
+    for (i, &ac) in a_chars.iter().enumerate() {
+        let prev = &matrix[i];
+        let mut row: Vec<usize> = Vec::with_capacity(b_chars.len() + 1);
+        row.push(i + 1);
+        for (j, &bc) in b_chars.iter().enumerate() {
+            let cost = if ac == bc { 0 } else { 1 };
+            let deletion = prev[j + 1] + 1;
+            let insertion = row[j] + 1;
+            let substitution = prev[j] + cost;
+            row.push(deletion.min(insertion).min(substitution));
+        }
+        matrix.push(row);
+    }
+
+    matrix
+}
+
+// Reusable Levenshtein scratch space. The DP row and decoded characters are
+// kept between calls, so computing many distances in a loop allocates only
+// when a longer input than any seen before comes along. Runs the same DP as
+// `distance_slice`, affix trimming and shorter-side row included.
+#[derive(Debug, Clone, Default)]
+pub struct Levenshtein {
+    row: Vec<usize>,
+    a_buf: Vec<char>,
+    b_buf: Vec<char>,
+}
+
+impl Levenshtein {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Same result as the free `distance` function, reusing internal buffers.
+    pub fn distance(&mut self, a: &str, b: &str) -> usize {
+        self.a_buf.clear();
+        self.a_buf.extend(a.chars());
+        self.b_buf.clear();
+        self.b_buf.extend(b.chars());
+        distance_slice_in(&self.a_buf, &self.b_buf, &mut self.row)
+    }
+
+    // Distance from `query` to each candidate, in input order. The query is
+    // decoded once and the DP rows are shared by every candidate.
+    pub fn distances(&mut self, query: &str, candidates: &[&str]) -> Vec<usize> {
+        self.a_buf.clear();
+        self.a_buf.extend(query.chars());
+        candidates
+            .iter()
+            .map(|c| {
+                self.b_buf.clear();
+                self.b_buf.extend(c.chars());
+                distance_slice_in(&self.a_buf, &self.b_buf, &mut self.row)
+            })
+            .collect()
+    }
+
+    // Distance from an already-decoded query to `candidate`.
+    #[cfg(feature = "rayon")]
+    fn distance_to(&mut self, query: &[char], candidate: &str) -> usize {
+        self.b_buf.clear();
+        self.b_buf.extend(candidate.chars());
+        distance_slice_in(query, &self.b_buf, &mut self.row)
+    }
+}
+
+// A string decoded to characters once, for comparing many sub-ranges of it
+// (e.g. windows of a document) without decoding each slice again. Ranges
+// are in characters, not bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CharBuf(Vec<char>);
+
+impl CharBuf {
+    pub fn new(s: &str) -> Self {
+        CharBuf(s.chars().collect())
+    }
+
+    // Number of characters in the buffer.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn chars(&self) -> &[char] {
+        &self.0
+    }
+
+    // Levenshtein distance between the characters in ranges `a` and `b`,
+    // which may overlap. Panics if either range is out of bounds.
+    pub fn distance_range(&self, a: Range<usize>, b: Range<usize>) -> usize {
+        distance_chars(&self.0[a], &self.0[b])
+    }
+}
+
+impl From<&str> for CharBuf {
+    fn from(s: &str) -> Self {
+        CharBuf::new(s)
+    }
+}
+
+// Distance from one query to many candidates, returned in input order.
+pub fn distances(query: &str, candidates: &[&str]) -> Vec<usize> {
+    Levenshtein::new().distances(query, candidates)
+}
+
+// Parallel version of `distances` (requires the `rayon` feature). Each worker
+// thread keeps its own scratch buffers; output order matches `candidates`.
+#[cfg(feature = "rayon")]
+pub fn par_distances(query: &str, candidates: &[&str]) -> Vec<usize> {
+    use rayon::prelude::*;
+
+    let query_chars: Vec<char> = query.chars().collect();
+    candidates
+        .par_iter()
+        .map_init(Levenshtein::new, |lev, c| lev.distance_to(&query_chars, c))
+        .collect()
+}
+
+// Incremental substring matcher for streamed text: the pattern is fixed and
+// text arrives one character at a time through `push`. This is the DP of
+// `partial_distance` run one text column per call (a match may start
+// anywhere in the text), so scanning a log stream never revisits input.
+// Memory is O(pattern length) regardless of how much text is pushed.
+#[derive(Debug, Clone)]
+pub struct Incremental {
+    pattern: Vec<char>,
+    // row[j]: best distance of the first `j` pattern characters against a
+    // substring of the text ending at the last pushed character
+    row: Vec<usize>,
+    best: usize,
+}
+
+impl Incremental {
+    pub fn new(pattern: &str) -> Self {
+        let pattern: Vec<char> = pattern.chars().collect();
+        let row = (0..=pattern.len()).collect();
+        let best = pattern.len();
+        Incremental { pattern, row, best }
+    }
+
+    // Feeds one character of text and returns the running best, i.e. what
+    // `best` returns: the distance of the pattern against its best match
+    // anywhere in the text seen so far. `last` gives the distance of the best
+    // match ending at this character instead.
+    pub fn push(&mut self, c: char) -> usize {
+        // row[0] stays 0: a match may start at any text position
+        let mut diag = self.row[0];
+        for (j, &pc) in self.pattern.iter().enumerate() {
+            let cost = if pc == c { 0 } else { 1 };
+            let above = self.row[j + 1];
+            let skip_text = above + 1;
+            let skip_pattern = self.row[j] + 1;
+            let substitution = diag + cost;
+            self.row[j + 1] = skip_text.min(skip_pattern).min(substitution);
+            diag = above;
+        }
+        self.best = self.best.min(self.last());
+        self.best
+    }
+
+    // Distance of the best match of the whole pattern ending at the last
+    // pushed character; the pattern length if nothing has been pushed.
+    pub fn last(&self) -> usize {
+        self.row[self.pattern.len()]
+    }
+
+    // Best distance of the pattern against any substring of the text pushed
+    // so far; before any text this is the pattern length.
+    pub fn best(&self) -> usize {
+        self.best
+    }
+
+    // Forgets all pushed text, e.g. at the start of a new line.
+    pub fn reset(&mut self) {
+        for (j, cell) in self.row.iter_mut().enumerate() {
+            *cell = j;
+        }
+        self.best = self.pattern.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::{random_corpus, random_pair};
+    use alloc::format;
+    use alloc::string::ToString;
+
+    const EMPTY_CASES: [(&str, &str); 3] = [("", ""), ("", "a"), ("a", "")];
+
+    #[test]
+    fn empty_inputs_base() {
+        for (a, b) in EMPTY_CASES {
+            let expected = a.len().max(b.len());
+            assert_eq!(distance(a, b), expected);
+            assert_eq!(similarity(a, b), 0);
+            let nd = normalized_distance(a, b);
+            let ns = normalized_similarity(a, b);
+            assert!(!nd.is_nan() && !ns.is_nan(), "{a:?} {b:?}");
+            assert_eq!(nd + ns, 1.0, "{a:?} {b:?}");
+        }
+        assert_eq!(normalized_distance("", ""), 0.0);
+        assert_eq!(normalized_similarity("", ""), 1.0);
+        assert_eq!(normalized_distance("", "a"), 1.0);
+    }
+
+    #[test]
+    fn empty_inputs_partial() {
+        for (a, b) in EMPTY_CASES {
+            assert_eq!(partial_distance(a, b), 0);
+            assert_eq!(partial_similarity(a, b), 0);
+            let nd = normalized_partial_distance(a, b);
+            let ns = normalized_partial_similarity(a, b);
+            assert!(!nd.is_nan() && !ns.is_nan(), "{a:?} {b:?}");
+            assert_eq!(nd + ns, 1.0, "{a:?} {b:?}");
+        }
+        assert_eq!(normalized_partial_distance("", ""), 0.0);
+        assert_eq!(normalized_partial_similarity("", ""), 1.0);
+        assert_eq!(normalized_partial_distance("", "a"), 1.0);
+    }
+
+    #[test]
+    fn normalized_pairs_sum_to_one() {
+        let pairs = [
+            ("abc", "abd"),
+            ("kitten", "sitting"),
+            ("flaw", "lawn"),
+            ("a", "bcd"),
+        ];
+        for (a, b) in pairs {
+            assert_eq!(normalized_distance(a, b) + normalized_similarity(a, b), 1.0);
+            assert_eq!(
+                normalized_partial_distance(a, b) + normalized_partial_similarity(a, b),
+                1.0
+            );
+        }
+    }
+
+    #[test]
+    fn partial_single_char_against_long() {
+        assert_eq!(partial_distance("x", "abcdef"), 1);
+        assert_eq!(partial_distance("a", "bbbbba"), 0);
+        assert_eq!(partial_distance("abcdef", "x"), 1);
+        for (a, b) in [("x", "abcdef"), ("a", "bbbbba")] {
+            let nd = normalized_partial_distance(a, b);
+            assert!(nd.is_finite() && (0.0..=1.0).contains(&nd));
+        }
+        assert_eq!(normalized_partial_distance("x", "abcdef"), 1.0);
+    }
+
+    #[test]
+    fn incremental_push_returns_running_best() {
+        let mut inc = Incremental::new("abc");
+        let pushed: Vec<(usize, usize)> = "xabcxx"
+            .chars()
+            .map(|c| (inc.push(c), inc.last()))
+            .collect();
+        assert_eq!(pushed, [(3, 3), (2, 2), (1, 1), (0, 0), (0, 1), (0, 2)]);
+        assert_eq!(inc.best(), partial_distance("abc", "xabcxx"));
+        inc.reset();
+        assert_eq!((inc.best(), inc.last()), (3, 3));
+    }
+
+    #[test]
+    fn default_weights_match_distance() {
+        let w = Weights::default();
+        for (a, b) in random_corpus() {
+            assert_eq!(
+                weighted_distance(&a, &b, &w),
+                distance(&a, &b),
+                "{a:?} {b:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn ignore_case_lowercases_both_sides() {
+        assert_eq!(distance_ignore_case("John", "john"), 0);
+        assert_eq!(distance_ignore_case("HELLO", "hello"), 0);
+        assert_eq!(normalized_distance_ignore_case("ABC", "abd"), 1.0 / 3.0);
+        // Lowercasing does not expand 'ß' to "ss"
+        assert_eq!(distance_ignore_case("STRASSE", "straße"), 2);
+    }
+
+    #[test]
+    fn distance_bytes_matches_distance_on_ascii() {
+        for (a, b) in random_corpus() {
+            assert_eq!(distance_bytes(a.as_bytes(), b.as_bytes()), distance(&a, &b));
+        }
+        let (a, b) = random_pair(9, 2000, 0.1);
+        assert_eq!(distance_bytes(a.as_bytes(), b.as_bytes()), distance(&a, &b));
+        // Bytes, not chars: 'é' is two bytes in UTF-8
+        assert_eq!(distance_bytes("é".as_bytes(), "e".as_bytes()), 2);
+    }
+
+    // The sliding-window `partial_distance` that the single-sweep DP
+    // replaced: full distance against every window of the shorter length.
+    fn windowed_partial_distance(a: &str, b: &str) -> usize {
+        let a_chars: Vec<char> = a.chars().collect();
+        let b_chars: Vec<char> = b.chars().collect();
+        let (short, long) = if a_chars.len() <= b_chars.len() {
+            (&a_chars, &b_chars)
+        } else {
+            (&b_chars, &a_chars)
+        };
+        if short.is_empty() {
+            return 0;
+        }
+        long.windows(short.len())
+            .map(|window| distance_chars(short, window))
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn partial_distance_agrees_with_windowed() {
+        for (seed, (a, b)) in random_corpus().enumerate() {
+            let (_, noise) = random_pair(seed as u64 + 1000, 10, 1.0);
+            let (left, right) = noise.split_at(noise.len() / 2);
+            let haystack = format!("{left}{a}{right}");
+            assert_eq!(partial_distance(&a, &haystack), 0);
+            assert_eq!(windowed_partial_distance(&a, &haystack), 0);
+            // Free-length substrings can only do better than fixed windows
+            assert!(partial_distance(&a, &b) <= windowed_partial_distance(&a, &b));
+        }
+        for (a, b) in [("abcd", "abce"), ("kitten", "sittin"), ("aaaa", "aaaa")] {
+            assert_eq!(partial_distance(a, b), windowed_partial_distance(a, b));
+        }
+    }
+
+    #[test]
+    fn partial_empty_against_non_empty() {
+        for (a, b) in [("", "abc"), ("abc", "")] {
+            assert_eq!(partial_distance(a, b), 0);
+            assert_eq!(partial_similarity(a, b), 0);
+            assert_eq!(normalized_partial_distance(a, b), 1.0);
+            assert_eq!(normalized_partial_similarity(a, b), 0.0);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn metrics_json_round_trip() {
+        let m = metrics("kitten", "sitting");
+        let json = serde_json::to_string(&m).unwrap();
+        assert!(json.contains("\"distance\":3"));
+        let back: Metrics = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, m);
+    }
+
+    #[test]
+    fn unit_substitution_cost_matches_distance() {
+        for (a, b) in random_corpus() {
+            let d = distance_with_cost(&a, &b, |x, y| (x != y) as usize);
+            assert_eq!(d, distance(&a, &b), "{a:?} {b:?}");
+        }
+    }
+
+    // Textbook full-matrix Levenshtein, no trimming or row reuse.
+    fn reference_distance(a: &str, b: &str) -> usize {
+        let a: Vec<char> = a.chars().collect();
+        let b: Vec<char> = b.chars().collect();
+        let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+        for (i, row) in d.iter_mut().enumerate() {
+            row[0] = i;
+        }
+        d[0] = (0..=b.len()).collect();
+        for i in 1..=a.len() {
+            for j in 1..=b.len() {
+                let cost = (a[i - 1] != b[j - 1]) as usize;
+                d[i][j] = (d[i - 1][j] + 1)
+                    .min(d[i][j - 1] + 1)
+                    .min(d[i - 1][j - 1] + cost);
+            }
+        }
+        d[a.len()][b.len()]
+    }
+
+    #[test]
+    fn single_row_matches_reference() {
+        for (a, b) in random_corpus() {
+            assert_eq!(distance(&a, &b), reference_distance(&a, &b), "{a:?} {b:?}");
+            assert_eq!(distance(&b, &a), reference_distance(&a, &b));
+        }
+        assert_eq!(distance("kitten", "sitting"), 3);
+        assert_eq!(distance("héllo wörld", "hello world"), 2);
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn simd_bytes_match_scalar() {
+        for seed in 0..300u64 {
+            let len = SIMD_MIN_LEN + (seed as usize % 200);
+            let (a, b) = random_pair(seed, len, (seed % 4) as f64 * 0.1);
+            // Multiplying by an odd number permutes the byte values, so the
+            // distance is unchanged while lanes also see non-ASCII bytes
+            let scramble = |s: &str| -> Vec<u8> {
+                s.bytes().map(|c| c.wrapping_mul(seed as u8 | 1)).collect()
+            };
+            let (a, b) = (scramble(&a), scramble(&b));
+            if a.len().min(b.len()) >= SIMD_MIN_LEN {
+                assert_eq!(
+                    distance_bytes_simd(&a, &b),
+                    distance_slice(&a, &b),
+                    "seed {seed}"
+                );
+            }
+            assert_eq!(
+                distance_bytes(&a, &b),
+                distance_slice(&a, &b),
+                "seed {seed}"
+            );
+        }
+    }
+
+    #[test]
+    fn capped_is_min_of_distance_and_cap() {
+        for (a, b) in random_corpus() {
+            let d = distance(&a, &b);
+            for cap in [0, 1, 2, 5, d, d + 1, 100, usize::MAX] {
+                assert_eq!(
+                    distance_capped(&a, &b, cap),
+                    d.min(cap),
+                    "{a:?} {b:?} {cap}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn partial_symmetric_on_equal_lengths() {
+        let mut pairs: Vec<(String, String)> = random_corpus()
+            .filter(|(a, b)| a.chars().count() == b.chars().count())
+            .collect();
+        pairs.extend(
+            [("abcd", "bcda"), ("xab", "abx"), ("ab", "ba")]
+                .map(|(a, b)| (a.to_string(), b.to_string())),
+        );
+        assert!(pairs.len() > 20);
+        for (a, b) in &pairs {
+            assert_eq!(
+                partial_distance(a, b),
+                partial_distance(b, a),
+                "{a:?} {b:?}"
+            );
+            assert_eq!(partial_similarity(a, b), partial_similarity(b, a));
+            assert_eq!(
+                normalized_partial_distance(a, b),
+                normalized_partial_distance(b, a)
+            );
+            assert_eq!(
+                normalized_partial_similarity(a, b),
+                normalized_partial_similarity(b, a)
+            );
+        }
+        assert_eq!(partial_distance("ab", "ba"), 1);
+    }
+
+    #[test]
+    fn identical_inputs_fast_path() {
+        assert_eq!(distance("abc", "abc"), 0);
+        assert_eq!(distance("", ""), 0);
+        assert_eq!(similarity("abc", "abc"), 3);
+        assert_eq!(similarity("", ""), 0);
+        assert_eq!(normalized_distance("abc", "abc"), 0.0);
+        assert_eq!(normalized_similarity("abc", "abc"), 1.0);
+        let long = "ab".repeat(5000);
+        assert_eq!(distance(&long, &long), 0);
+    }
+
+    // The source and target strings an edit script spells out.
+    fn script_sides(script: &[Edit]) -> (String, String) {
+        let mut from = String::new();
+        let mut to = String::new();
+        for edit in script {
+            match *edit {
+                Edit::Match(c) => {
+                    from.push(c);
+                    to.push(c);
+                }
+                Edit::Substitute(x, y) => {
+                    from.push(x);
+                    to.push(y);
+                }
+                Edit::Insert(c) => to.push(c),
+                Edit::Delete(c) => from.push(c),
+            }
+        }
+        (from, to)
+    }
+
+    #[test]
+    fn linear_script_is_optimal() {
+        let edits = |s: &[Edit]| s.iter().filter(|e| !matches!(e, Edit::Match(_))).count();
+        for (a, b) in random_corpus() {
+            assert_eq!(
+                edit_script_linear(&a, &b),
+                edit_script(&a, &b),
+                "{a:?} {b:?}"
+            );
+        }
+        assert_eq!(edit_script_linear("aa", "a"), edit_script("aa", "a"));
+        // Large enough to split below HIRSCHBERG_BASE_CELLS several times
+        let (a, b) = random_pair(7, 600, 0.2);
+        let linear = edit_script_linear(&a, &b);
+        assert_eq!(script_sides(&linear), (a.clone(), b.clone()));
+        assert_eq!(edits(&linear), distance(&a, &b));
+    }
+
+    #[test]
+    fn similarity_percent_rounds() {
+        assert_eq!(similarity_percent("", ""), 100);
+        assert_eq!(similarity_percent("abc", "abd"), 67);
+        assert_eq!(similarity_percent("abc", "xyz"), 0);
+    }
+
+    #[test]
+    fn scratch_struct_matches_distance() {
+        let mut lev = Levenshtein::new();
+        let pairs: Vec<(String, String)> = random_corpus().collect();
+        for (a, b) in &pairs {
+            assert_eq!(lev.distance(a, b), distance(a, b), "{a:?} {b:?}");
+        }
+        let candidates: Vec<&str> = pairs.iter().map(|(_, b)| b.as_str()).collect();
+        let expected: Vec<usize> = candidates.iter().map(|c| distance("query", c)).collect();
+        assert_eq!(lev.distances("query", &candidates), expected);
+        assert_eq!(distances("query", &candidates), expected);
+        #[cfg(feature = "rayon")]
+        assert_eq!(par_distances("query", &candidates), expected);
+    }
+
+    #[test]
+    fn banded_accepts_huge_bands() {
+        assert_eq!(distance_banded("kitten", "sitting", usize::MAX), Some(3));
+        assert_eq!(
+            distance_banded("kitten", "sitting", usize::MAX - 1),
+            Some(3)
+        );
+        assert_eq!(distance_banded("kitten", "sitting", 2), None);
+        for (a, b) in random_corpus() {
+            assert_eq!(distance_banded(&a, &b, usize::MAX), Some(distance(&a, &b)));
+        }
+    }
+
+    #[test]
+    fn capped_accepts_huge_caps() {
+        assert_eq!(distance_capped("kitten", "sitting", usize::MAX), 3);
     }
 }
@@ -0,0 +1,93 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+
+extern crate alloc;
+
+pub mod automaton;
+pub mod bktree;
+pub mod comparator;
+pub mod confusable;
+pub mod damerau;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod hamming;
+pub mod jaro;
+pub mod keyboard;
+pub mod lcs;
+pub mod levenshtein;
+pub mod metric;
+#[cfg(feature = "std")]
+pub mod myers;
+#[cfg(feature = "std")]
+pub mod ngram;
+pub mod phonetic;
+pub mod ratcliff;
+pub mod score;
+pub mod search;
+#[cfg(feature = "std")]
+pub mod symspell;
+#[cfg(any(test, feature = "testutil"))]
+pub mod testutil;
+pub mod token;
+#[cfg(feature = "unicode")]
+pub mod unicode;
+
+pub use automaton::LevAutomaton;
+pub use bktree::BkTree;
+pub use comparator::{Algorithm, Comparator};
+pub use confusable::confusable_distance;
+pub use damerau::osa_distance;
+pub use hamming::{hamming, normalized_hamming};
+pub use jaro::{jaro, jaro_winkler, jaro_winkler_config};
+pub use keyboard::qwerty_distance;
+pub use lcs::{
+    indel_distance, lcs_length, lcs_ratio, lcs_sequence, longest_common_substring,
+    longest_common_substring_str, normalized_indel_distance,
+};
+pub use levenshtein::{
+    CharBuf, DEFAULT_MAX_CELLS, DistanceError, Edit, Incremental, Levenshtein, Locale, Metrics,
+    Norm, Preference, SMALL_CAPACITY, Weights, align, all_matches, best_substring, distance,
+    distance_banded, distance_bytes, distance_capped, distance_chars, distance_ignore_case,
+    distance_ignore_case_locale, distance_iter, distance_lossy, distance_matrix, distance_slice,
+    distance_small, distance_strict, distance_trim_whitespace, distance_with_cost, distance_within,
+    distances, edit_script, edit_script_linear, edit_script_with, metrics, normalized_distance,
+    normalized_distance_alignment, normalized_distance_ignore_case, normalized_distance_mean,
+    normalized_partial_distance, normalized_partial_similarity, normalized_similarity,
+    normalized_similarity_by, partial_distance, partial_edit_script, partial_ratio,
+    partial_similarity, partial_similarity_ratio, prefix_weighted_distance, similarity,
+    similarity_percent, smart_distance, try_distance, try_distance_with_limit, weighted_distance,
+    weighted_similarity,
+};
+pub use metric::Metric;
+pub use phonetic::{
+    double_metaphone, metaphone_matches, nysiis, nysiis_matches, soundex, soundex_matches,
+};
+pub use ratcliff::{quick_ratio, ratcliff_obershelp};
+pub use score::{
+    Score, jaro_score, jaro_winkler_score, partial_similarity_score, similarity_score,
+};
+pub use search::{best_match, cluster, matches_within, top_k};
+pub use token::{
+    partial_token_set_ratio, partial_token_sort_ratio, token_distance, token_set_ratio,
+    token_sort_ratio, weighted_ratio,
+};
+
+#[cfg(feature = "std")]
+pub use damerau::damerau_distance;
+#[cfg(feature = "rayon")]
+pub use levenshtein::par_distances;
+#[cfg(feature = "std")]
+pub use myers::{MyersPattern, distance_myers};
+#[cfg(feature = "std")]
+pub use ngram::{
+    NgramOpts, cosine, cosine_with, dice, dice_n, dice_with, jaccard, jaccard_with, ngrams,
+    overlap, overlap_with, tversky, tversky_with,
+};
+#[cfg(feature = "std")]
+pub use symspell::SymSpell;
+#[cfg(feature = "testutil")]
+pub use testutil::{random_corpus, random_pair};
+#[cfg(feature = "unicode")]
+pub use unicode::{
+    align_display_width, distance_ascii_fold, distance_graphemes, distance_normalized_nfc,
+};
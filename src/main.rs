@@ -1,12 +1,141 @@
-mod levenshtein;
-
-fn main() {
-    println!("{}", levenshtein::distance("john wick", "john wicker"));
-    println!("{}", levenshtein::normalized_distance("john wick", "john wicker"));
-    println!("{}", levenshtein::similarity("john wick", "john wicker"));
-    println!("{}", levenshtein::normalized_similarity("john wick", "john wicker"));
-    println!("{}", levenshtein::partial_distance("john wick", "john wicker"));
-    println!("{}", levenshtein::normalized_partial_distance("john wick", "john wicker"));
-    println!("{}", levenshtein::partial_similarity("john wick", "john wicker"));
-    println!("{}", levenshtein::normalized_partial_similarity("john wick", "john wicker"));
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, ValueEnum};
+use rstring::{jaro, levenshtein};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Metric {
+    Distance,
+    Similarity,
+    Jaro,
+}
+
+/// Compare two strings, or score tab-separated pairs read from a file or stdin.
+#[derive(Debug, Parser)]
+#[command(version)]
+struct Args {
+    /// First string (omit both strings to read `a<TAB>b` lines and write
+    /// `a<TAB>b<TAB>score` lines)
+    #[arg(requires = "b", conflicts_with = "file")]
+    a: Option<String>,
+
+    /// Second string
+    b: Option<String>,
+
+    /// Metric to compute
+    #[arg(long, value_enum, default_value_t = Metric::Distance)]
+    metric: Metric,
+
+    /// Normalize the score to [0.0, 1.0]
+    #[arg(long)]
+    normalized: bool,
+
+    /// Compare the shorter string against the best-matching substring of the longer
+    #[arg(long)]
+    partial: bool,
+
+    /// Lowercase both strings before comparing
+    #[arg(long)]
+    ignore_case: bool,
+
+    /// Read pairs from this file instead of stdin
+    #[arg(long, value_name = "PATH")]
+    file: Option<PathBuf>,
+
+    /// Exit with a failure status if any input line lacks a tab (such lines
+    /// are always skipped with a warning)
+    #[arg(long)]
+    strict: bool,
+}
+
+// Computes the selected metric for one pair, formatted for output.
+fn score(args: &Args, a: &str, b: &str) -> String {
+    let (a, b) = if args.ignore_case {
+        (a.to_lowercase(), b.to_lowercase())
+    } else {
+        (a.to_string(), b.to_string())
+    };
+    let (a, b) = (a.as_str(), b.as_str());
+
+    match (args.metric, args.partial, args.normalized) {
+        (Metric::Distance, false, false) => levenshtein::distance(a, b).to_string(),
+        (Metric::Distance, false, true) => levenshtein::normalized_distance(a, b).to_string(),
+        (Metric::Distance, true, false) => levenshtein::partial_distance(a, b).to_string(),
+        (Metric::Distance, true, true) => {
+            levenshtein::normalized_partial_distance(a, b).to_string()
+        }
+        (Metric::Similarity, false, false) => levenshtein::similarity(a, b).to_string(),
+        (Metric::Similarity, false, true) => levenshtein::normalized_similarity(a, b).to_string(),
+        (Metric::Similarity, true, false) => levenshtein::partial_similarity(a, b).to_string(),
+        (Metric::Similarity, true, true) => {
+            levenshtein::normalized_partial_similarity(a, b).to_string()
+        }
+        // Jaro is already in [0.0, 1.0]; --partial is rejected up front
+        (Metric::Jaro, _, _) => jaro::jaro(a, b).to_string(),
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    if args.partial && args.metric == Metric::Jaro {
+        eprintln!("error: --partial is not supported with --metric jaro");
+        return ExitCode::FAILURE;
+    }
+
+    if let (Some(a), Some(b)) = (&args.a, &args.b) {
+        println!("{}", score(&args, a, b));
+        return ExitCode::SUCCESS;
+    }
+
+    let input: Box<dyn BufRead> = match &args.file {
+        Some(path) => match File::open(path) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(e) => {
+                eprintln!("error: opening {}: {e}", path.display());
+                return ExitCode::FAILURE;
+            }
+        },
+        None => Box::new(io::stdin().lock()),
+    };
+    let source = match &args.file {
+        Some(path) => path.display().to_string(),
+        None => "stdin".to_string(),
+    };
+
+    let stdout = io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    let mut skipped = 0;
+    for (n, line) in input.lines().enumerate() {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => {
+                eprintln!("error: reading {source}: {e}");
+                return ExitCode::FAILURE;
+            }
+        };
+        let Some((a, b)) = line.split_once('\t') else {
+            eprintln!(
+                "warning: line {}: expected two tab-separated strings, skipping",
+                n + 1
+            );
+            skipped += 1;
+            continue;
+        };
+        if writeln!(out, "{line}\t{}", score(&args, a, b)).is_err() {
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if out.flush().is_err() {
+        return ExitCode::FAILURE;
+    }
+    if args.strict && skipped > 0 {
+        eprintln!("error: {skipped} malformed line(s) in {source}");
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
 }
@@ -0,0 +1,81 @@
+#[cfg(feature = "std")]
+use crate::damerau::damerau_distance;
+use crate::damerau::osa_distance;
+use crate::jaro::{jaro, jaro_winkler};
+use crate::levenshtein::distance;
+#[cfg(feature = "std")]
+use crate::ngram::dice;
+
+// A string distance usable generically, e.g. as `&dyn Metric`. Lower is
+// closer and 0.0 means identical. Edit distances report their edit count;
+// similarity-based metrics report `1.0 - similarity`, so they fall in
+// [0.0, 1.0]. The free functions remain the simplest way to call any one
+// metric directly.
+pub trait Metric {
+    fn distance(&self, a: &str, b: &str) -> f64;
+}
+
+// Levenshtein edit count (`distance`). Named apart from the crate's
+// reusable-buffer `Levenshtein` so both can be imported together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LevenshteinMetric;
+
+impl Metric for LevenshteinMetric {
+    fn distance(&self, a: &str, b: &str) -> f64 {
+        distance(a, b) as f64
+    }
+}
+
+// Unrestricted Damerau-Levenshtein edit count (`damerau_distance`).
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DamerauLevenshtein;
+
+#[cfg(feature = "std")]
+impl Metric for DamerauLevenshtein {
+    fn distance(&self, a: &str, b: &str) -> f64 {
+        damerau_distance(a, b) as f64
+    }
+}
+
+// Optimal string alignment edit count (`osa_distance`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OptimalStringAlignment;
+
+impl Metric for OptimalStringAlignment {
+    fn distance(&self, a: &str, b: &str) -> f64 {
+        osa_distance(a, b) as f64
+    }
+}
+
+// `1.0 - jaro(a, b)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Jaro;
+
+impl Metric for Jaro {
+    fn distance(&self, a: &str, b: &str) -> f64 {
+        1.0 - jaro(a, b)
+    }
+}
+
+// `1.0 - jaro_winkler(a, b)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct JaroWinkler;
+
+impl Metric for JaroWinkler {
+    fn distance(&self, a: &str, b: &str) -> f64 {
+        1.0 - jaro_winkler(a, b)
+    }
+}
+
+// `1.0 - dice(a, b)`, over character bigrams.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Dice;
+
+#[cfg(feature = "std")]
+impl Metric for Dice {
+    fn distance(&self, a: &str, b: &str) -> f64 {
+        1.0 - dice(a, b)
+    }
+}
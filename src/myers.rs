@@ -0,0 +1,166 @@
+use alloc::{vec, vec::Vec};
+use std::collections::HashMap;
+
+const WORD_BITS: usize = 64;
+
+// Levenshtein distance using Myers' bit-parallel algorithm, extended to
+// multiple 64-bit blocks (Hyyrö). The shorter string is the pattern; each of
+// its characters gets an equality mask, and the DP column is encoded as
+// vertical +1/-1 deltas, so each text character costs ceil(m / 64) word
+// operations instead of m cell updates. Returns exactly `distance(a, b)`.
+pub fn distance_myers(a: &str, b: &str) -> usize {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    myers_chars(&a_chars, &b_chars)
+}
+
+// `distance_myers` over decoded characters.
+pub(crate) fn myers_chars(a_chars: &[char], b_chars: &[char]) -> usize {
+    let (pattern, text) = if a_chars.len() <= b_chars.len() {
+        (a_chars, b_chars)
+    } else {
+        (b_chars, a_chars)
+    };
+
+    MyersPattern::from_chars(pattern).distance_chars(text)
+}
+
+// A pattern's precomputed per-character equality masks for `distance_myers`,
+// built once and reused against any number of texts, like `Levenshtein`
+// reuses its buffers for the plain DP. Each `distance` call then only
+// allocates the two column vectors, ceil(len / 64) words each. Works for
+// texts both shorter and longer than the pattern, though the scan is
+// cheapest when the pattern is the shorter side.
+#[derive(Debug, Clone)]
+pub struct MyersPattern {
+    len: usize,
+    blocks: usize,
+    peq: HashMap<char, Vec<u64>>,
+}
+
+impl MyersPattern {
+    pub fn new(pattern: &str) -> Self {
+        let chars: Vec<char> = pattern.chars().collect();
+        Self::from_chars(&chars)
+    }
+
+    fn from_chars(pattern: &[char]) -> Self {
+        let blocks = pattern.len().div_ceil(WORD_BITS);
+        let mut peq: HashMap<char, Vec<u64>> = HashMap::new();
+        for (i, &c) in pattern.iter().enumerate() {
+            peq.entry(c).or_insert_with(|| vec![0; blocks])[i / WORD_BITS] |= 1 << (i % WORD_BITS);
+        }
+        MyersPattern {
+            len: pattern.len(),
+            blocks,
+            peq,
+        }
+    }
+
+    // Pattern length in characters.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Levenshtein distance from the pattern to `text`.
+    pub fn distance(&self, text: &str) -> usize {
+        let chars: Vec<char> = text.chars().collect();
+        self.distance_chars(&chars)
+    }
+
+    fn distance_chars(&self, text: &[char]) -> usize {
+        let (m, blocks) = (self.len, self.blocks);
+        if m == 0 {
+            return text.len();
+        }
+        let no_match: Vec<u64> = vec![0; blocks];
+
+        // The last block may be partially used; its top row is pattern row m
+        let last_high = 1u64 << ((m - 1) % WORD_BITS);
+        let mut pv: Vec<u64> = vec![u64::MAX; blocks];
+        let mut mv: Vec<u64> = vec![0; blocks];
+        let mut score = m;
+
+        for c in text {
+            let eq = self.peq.get(c).unwrap_or(&no_match);
+            // Row 0 of the DP is 0, 1, 2, ... so it always enters with +1
+            let mut carry = 1;
+            for blk in 0..blocks {
+                let high = if blk + 1 == blocks {
+                    last_high
+                } else {
+                    1 << (WORD_BITS - 1)
+                };
+                carry = advance_block(&mut pv[blk], &mut mv[blk], eq[blk], carry, high);
+            }
+            score = score.wrapping_add_signed(carry as isize);
+        }
+
+        score
+    }
+}
+
+// Advances one 64-row block of the DP column by one text character.
+// `hin` is the horizontal delta (-1, 0 or +1) entering the block's first
+// row; the delta leaving the row marked by `high` is returned.
+fn advance_block(pv: &mut u64, mv: &mut u64, eq: u64, hin: i32, high: u64) -> i32 {
+    let (p, m) = (*pv, *mv);
+    let hin_neg = (hin < 0) as u64;
+
+    let xv = eq | m;
+    let eq = eq | hin_neg;
+    let xh = ((eq & p).wrapping_add(p) ^ p) | eq;
+    let mut ph = m | !(xh | p);
+    let mut mh = p & xh;
+
+    let hout = if ph & high != 0 {
+        1
+    } else if mh & high != 0 {
+        -1
+    } else {
+        0
+    };
+
+    ph <<= 1;
+    mh <<= 1;
+    mh |= hin_neg;
+    if hin > 0 {
+        ph |= 1;
+    }
+
+    *pv = mh | !(xv | ph);
+    *mv = ph & xv;
+    hout
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levenshtein::distance;
+    use crate::testutil::random_pair;
+
+    #[test]
+    fn myers_matches_distance_across_blocks() {
+        for seed in 0..60u64 {
+            // 60..=295 chars: one to five 64-bit blocks
+            let len = 60 + (seed as usize * 4);
+            let (a, b) = random_pair(seed, len, 0.15);
+            assert_eq!(distance_myers(&a, &b), distance(&a, &b), "seed {seed}");
+            assert_eq!(MyersPattern::new(&b).distance(&a), distance(&a, &b));
+        }
+        assert_eq!(distance_myers(&"a".repeat(64), &"a".repeat(65)), 1);
+        assert_eq!(distance_myers(&"a".repeat(130), ""), 130);
+    }
+
+    #[test]
+    fn myers_handles_unicode() {
+        let a = "🦀ßé".repeat(30);
+        let b = "🦀sé".repeat(31);
+        assert_eq!(distance_myers(&a, &b), distance(&a, &b));
+        assert_eq!(distance_myers("naïve", "naive"), 1);
+    }
+}
@@ -0,0 +1,279 @@
+use alloc::string::String;
+use alloc::{vec, vec::Vec};
+use std::collections::{HashMap, HashSet};
+
+// Boundary marker added around padded strings; a control character so it
+// never collides with ordinary text.
+const PAD: char = '\u{2}';
+
+// Preprocessing applied by the `*_with` metrics (`dice_with`, `jaccard_with`,
+// `overlap_with`, `tversky_with` and `cosine_with`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NgramOpts {
+    // Lowercase both strings before splitting into grams.
+    pub ignore_case: bool,
+    // Wrap non-empty strings in `n - 1` boundary markers on each side, so
+    // the first and last characters appear in as many grams as inner ones.
+    pub pad: bool,
+}
+
+// The characters of `s` after applying `opts` for grams of length `n`.
+fn prepare(s: &str, n: usize, opts: &NgramOpts) -> Vec<char> {
+    let mut chars: Vec<char> = if opts.ignore_case {
+        s.chars().flat_map(char::to_lowercase).collect()
+    } else {
+        s.chars().collect()
+    };
+    if opts.pad && !chars.is_empty() && n > 1 {
+        let pad = n - 1;
+        chars.splice(0..0, core::iter::repeat_n(PAD, pad));
+        chars.extend(core::iter::repeat_n(PAD, pad));
+    }
+    chars
+}
+
+// The grams of `chars` for length `n`, shared by `ngrams` and every metric in
+// this module: the sliding windows of `n` characters, except that a
+// non-empty input shorter than `n` is one gram covering all of it. `n` of 0
+// and the empty input give no grams.
+fn windows(chars: &[char], n: usize) -> Vec<&[char]> {
+    if n == 0 || chars.is_empty() {
+        Vec::new()
+    } else if chars.len() < n {
+        vec![chars]
+    } else {
+        chars.windows(n).collect()
+    }
+}
+
+// The sliding windows of `n` characters over `s`, in order and with
+// repeats, e.g. "abcd" with n = 2 gives ["ab", "bc", "cd"]. These are the
+// grams behind the metrics in this module, made available for building
+// custom ones. A non-empty string shorter than `n` is returned whole as a
+// single gram, which is how `jaccard`, `overlap` and `tversky` score it;
+// `dice_n` and `cosine` instead treat such a string as having no grams (see
+// their fallbacks). `n` of 0 and the empty string give no grams.
+pub fn ngrams(s: &str, n: usize) -> Vec<String> {
+    let chars: Vec<char> = s.chars().collect();
+    windows(&chars, n)
+        .into_iter()
+        .map(|gram| gram.iter().collect())
+        .collect()
+}
+
+// Counts of each character n-gram of `chars`, as split by `windows`.
+fn gram_counts(chars: &[char], n: usize) -> HashMap<&[char], usize> {
+    let mut counts: HashMap<&[char], usize> = HashMap::new();
+    for gram in windows(chars, n) {
+        *counts.entry(gram).or_insert(0) += 1;
+    }
+    counts
+}
+
+// Squared Euclidean norm of a gram count vector.
+fn squared_norm(counts: &HashMap<&[char], usize>) -> f64 {
+    counts.values().map(|&c| (c * c) as f64).sum()
+}
+
+// Set of distinct character n-grams. A non-empty string shorter than `n`
+// is treated as a single gram covering the whole string.
+fn gram_set(chars: &[char], n: usize) -> HashSet<&[char]> {
+    windows(chars, n).into_iter().collect()
+}
+
+// Sørensen-Dice coefficient over character bigrams, in [0.0, 1.0].
+pub fn dice(a: &str, b: &str) -> f64 {
+    dice_n(a, b, 2)
+}
+
+// Sørensen-Dice coefficient over character n-grams:
+// 2 * |intersection| / (|grams_a| + |grams_b|), where repeated grams count
+// as many times as they appear in both strings (multiset intersection).
+// If either string is shorter than `n` (or `n` is 0) there are no grams to
+// compare, so the result falls back to exact equality: 1.0 or 0.0.
+pub fn dice_n(a: &str, b: &str, n: usize) -> f64 {
+    dice_with(a, b, n, &NgramOpts::default())
+}
+
+// `dice_n` after case folding and/or boundary padding as set in `opts`.
+// Padding also gives strings shorter than `n` grams of their own, so they
+// no longer fall back to exact equality.
+pub fn dice_with(a: &str, b: &str, n: usize, opts: &NgramOpts) -> f64 {
+    let a_chars = prepare(a, n, opts);
+    let b_chars = prepare(b, n, opts);
+
+    if n == 0 || a_chars.len() < n || b_chars.len() < n {
+        return if a_chars == b_chars { 1.0 } else { 0.0 };
+    }
+
+    let a_grams = gram_counts(&a_chars, n);
+    let b_grams = gram_counts(&b_chars, n);
+
+    let shared: usize = a_grams
+        .iter()
+        .map(|(gram, &count)| count.min(b_grams.get(gram).copied().unwrap_or(0)))
+        .sum();
+    let total = (a_chars.len() - n + 1) + (b_chars.len() - n + 1);
+
+    2.0 * shared as f64 / total as f64
+}
+
+// Jaccard similarity over the sets of character n-grams:
+// |A ∩ B| / |A ∪ B|, in [0.0, 1.0]. A string shorter than `n` counts as a
+// single gram of the whole string, and two empty strings are identical
+// (1.0). With `n` of 0 there are no grams, so this is exact equality.
+pub fn jaccard(a: &str, b: &str, n: usize) -> f64 {
+    jaccard_with(a, b, n, &NgramOpts::default())
+}
+
+// `jaccard` after case folding and/or boundary padding as set in `opts`.
+pub fn jaccard_with(a: &str, b: &str, n: usize, opts: &NgramOpts) -> f64 {
+    let a_chars = prepare(a, n, opts);
+    let b_chars = prepare(b, n, opts);
+
+    if n == 0 {
+        return if a_chars == b_chars { 1.0 } else { 0.0 };
+    }
+
+    let a_grams = gram_set(&a_chars, n);
+    let b_grams = gram_set(&b_chars, n);
+
+    let union = a_grams.union(&b_grams).count();
+    if union == 0 {
+        return 1.0;
+    }
+    a_grams.intersection(&b_grams).count() as f64 / union as f64
+}
+
+// Overlap (Szymkiewicz-Simpson) coefficient over the sets of character
+// n-grams: |A ∩ B| / min(|A|, |B|), in [0.0, 1.0]. It is 1.0 whenever one
+// gram set contains the other, which suits short-vs-long comparisons. Gram
+// sets are built as in `jaccard`; two empty strings are 1.0, and one empty
+// string against a non-empty one is 0.0.
+pub fn overlap(a: &str, b: &str, n: usize) -> f64 {
+    overlap_with(a, b, n, &NgramOpts::default())
+}
+
+// `overlap` after case folding and/or boundary padding as set in `opts`.
+pub fn overlap_with(a: &str, b: &str, n: usize, opts: &NgramOpts) -> f64 {
+    let a_chars = prepare(a, n, opts);
+    let b_chars = prepare(b, n, opts);
+
+    if n == 0 {
+        return if a_chars == b_chars { 1.0 } else { 0.0 };
+    }
+
+    let a_grams = gram_set(&a_chars, n);
+    let b_grams = gram_set(&b_chars, n);
+
+    let min = a_grams.len().min(b_grams.len());
+    if min == 0 {
+        return if a_grams.len() == b_grams.len() { 1.0 } else { 0.0 };
+    }
+    a_grams.intersection(&b_grams).count() as f64 / min as f64
+}
+
+// Tversky index over the sets of character n-grams:
+// |A ∩ B| / (|A ∩ B| + alpha * |A - B| + beta * |B - A|). `alpha` and
+// `beta` weight the grams only in `a` and only in `b`, and should be
+// non-negative. alpha = beta = 1 gives `jaccard`; alpha = beta = 0.5 gives
+// the Dice coefficient over gram sets (`dice_n` instead counts repeated
+// grams). Unequal weights make the comparison asymmetric, e.g. a small
+// `alpha` barely penalizes `a` containing extra grams. Two empty strings are
+// 1.0; a zero denominator otherwise gives 0.0.
+pub fn tversky(a: &str, b: &str, n: usize, alpha: f64, beta: f64) -> f64 {
+    tversky_with(a, b, n, alpha, beta, &NgramOpts::default())
+}
+
+// `tversky` after case folding and/or boundary padding as set in `opts`.
+pub fn tversky_with(a: &str, b: &str, n: usize, alpha: f64, beta: f64, opts: &NgramOpts) -> f64 {
+    let a_chars = prepare(a, n, opts);
+    let b_chars = prepare(b, n, opts);
+
+    if n == 0 {
+        return if a_chars == b_chars { 1.0 } else { 0.0 };
+    }
+
+    let a_grams = gram_set(&a_chars, n);
+    let b_grams = gram_set(&b_chars, n);
+    if a_grams.is_empty() && b_grams.is_empty() {
+        return 1.0;
+    }
+
+    let shared = a_grams.intersection(&b_grams).count() as f64;
+    let only_a = a_grams.len() as f64 - shared;
+    let only_b = b_grams.len() as f64 - shared;
+
+    let denominator = shared + alpha * only_a + beta * only_b;
+    if denominator == 0.0 {
+        0.0
+    } else {
+        shared / denominator
+    }
+}
+
+// Cosine similarity of the character n-gram count vectors, in [0.0, 1.0]:
+// dot(A, B) / (|A| * |B|), so repeated grams weigh more, unlike the set-based
+// `jaccard` and `overlap`. A string shorter than `n` (or `n` of 0) has no
+// grams, and an empty vector on either side gives 0.0.
+pub fn cosine(a: &str, b: &str, n: usize) -> f64 {
+    cosine_with(a, b, n, &NgramOpts::default())
+}
+
+// `cosine` after case folding and/or boundary padding as set in `opts`.
+// Padding also gives strings shorter than `n` grams of their own, so they no
+// longer score 0.0.
+pub fn cosine_with(a: &str, b: &str, n: usize, opts: &NgramOpts) -> f64 {
+    let a_chars = prepare(a, n, opts);
+    let b_chars = prepare(b, n, opts);
+
+    if n == 0 || a_chars.len() < n || b_chars.len() < n {
+        return 0.0;
+    }
+
+    let a_grams = gram_counts(&a_chars, n);
+    let b_grams = gram_counts(&b_chars, n);
+
+    let dot: usize = a_grams
+        .iter()
+        .map(|(gram, &count)| count * b_grams.get(gram).copied().unwrap_or(0))
+        .sum();
+    // Both norms are non-zero since each string has at least one gram.
+    // Taking one square root of the product keeps identical inputs at 1.0.
+    let norms = (squared_norm(&a_grams) * squared_norm(&b_grams)).sqrt();
+    (dot as f64 / norms).min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ngrams_match_the_metric_grams() {
+        assert_eq!(ngrams("abcd", 2), ["ab", "bc", "cd"]);
+        assert_eq!(ngrams("a", 2), ["a"]);
+        assert!(ngrams("", 2).is_empty() && ngrams("abc", 0).is_empty());
+        // A short string is one whole gram for the set-based metrics...
+        assert_eq!(jaccard("a", "a", 2), 1.0);
+        assert_eq!(overlap("a", "ab", 2), 0.0);
+        // ...but has no grams for the count-based ones
+        assert_eq!(cosine("a", "a", 2), 0.0);
+        assert_eq!(dice_n("a", "b", 2), 0.0);
+    }
+
+    #[test]
+    fn with_opts_fold_case_and_pad() {
+        let fold = NgramOpts {
+            ignore_case: true,
+            pad: false,
+        };
+        assert_eq!(cosine_with("Night", "NIGHT", 2, &fold), 1.0);
+        assert_eq!(overlap_with("New", "new york", 2, &fold), 1.0);
+        assert_eq!(tversky_with("ABC", "abc", 2, 0.5, 0.5, &fold), 1.0);
+        let pad = NgramOpts {
+            ignore_case: false,
+            pad: true,
+        };
+        assert_eq!(cosine_with("a", "a", 2, &pad), 1.0);
+    }
+}
@@ -0,0 +1,501 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+// Soundex digit for an uppercase ASCII letter: Some('0') for vowels (and Y),
+// which separate repeated codes, and None for H and W, which do not.
+fn soundex_digit(c: char) -> Option<char> {
+    match c {
+        'B' | 'F' | 'P' | 'V' => Some('1'),
+        'C' | 'G' | 'J' | 'K' | 'Q' | 'S' | 'X' | 'Z' => Some('2'),
+        'D' | 'T' => Some('3'),
+        'L' => Some('4'),
+        'M' | 'N' => Some('5'),
+        'R' => Some('6'),
+        'H' | 'W' => None,
+        _ => Some('0'),
+    }
+}
+
+// American Soundex code: the first letter followed by three digits, e.g.
+// "Robert" and "Rupert" are both "R163". Vowels are dropped, adjacent letters
+// with the same code (including across H/W, and the first letter) are
+// merged, and the code is padded with zeros or truncated to four characters.
+// Non-ASCII-letters are ignored; input without any letters gives "".
+pub fn soundex(s: &str) -> String {
+    let mut letters = s
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_uppercase());
+
+    let Some(first) = letters.next() else {
+        return String::new();
+    };
+
+    let mut code = String::with_capacity(4);
+    code.push(first);
+
+    let mut last = soundex_digit(first);
+    for c in letters {
+        let digit = soundex_digit(c);
+        match digit {
+            None => continue,
+            Some('0') => {}
+            Some(d) if digit != last => {
+                code.push(d);
+                if code.len() == 4 {
+                    break;
+                }
+            }
+            Some(_) => {}
+        }
+        last = digit;
+    }
+
+    while code.len() < 4 {
+        code.push('0');
+    }
+    code
+}
+
+// True if both strings have the same, non-empty Soundex code.
+pub fn soundex_matches(a: &str, b: &str) -> bool {
+    let code = soundex(a);
+    !code.is_empty() && code == soundex(b)
+}
+
+// Longest key `double_metaphone` produces, as in the reference implementation.
+const METAPHONE_KEY_LEN: usize = 4;
+
+// Primary and alternate keys under construction.
+struct Keys {
+    primary: String,
+    alternate: String,
+}
+
+impl Keys {
+    fn push(&mut self, code: &str) {
+        self.push_both(code, code);
+    }
+
+    fn push_both(&mut self, primary: &str, alternate: &str) {
+        self.primary.push_str(primary);
+        self.alternate.push_str(alternate);
+    }
+
+    fn done(&self) -> bool {
+        self.primary.len() >= METAPHONE_KEY_LEN && self.alternate.len() >= METAPHONE_KEY_LEN
+    }
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'A' | 'E' | 'I' | 'O' | 'U' | 'Y')
+}
+
+// Character at `i`, or '\0' past either end.
+fn char_at(s: &[char], i: usize) -> char {
+    s.get(i).copied().unwrap_or('\0')
+}
+
+// True if `s[i..]` starts with any of `patterns`.
+fn starts_with_any(s: &[char], i: usize, patterns: &[&str]) -> bool {
+    patterns.iter().any(|p| {
+        let mut rest = s.get(i..).unwrap_or(&[]).iter();
+        p.chars().all(|c| rest.next() == Some(&c))
+    })
+}
+
+// Double Metaphone keys for `s`: a primary key and, where the spelling is
+// ambiguous (e.g. "Smith" may also be the Germanic "Schmidt"), an alternate.
+// Keys are at most four characters; "0" stands for "th" and "X" for "sh"/"ch".
+// This covers the common English rules (silent letters such as the K in "kn"
+// and the GH in "night", digraphs like "ph" -> F and "th" -> 0, soft C and G)
+// rather than every Slavic, Spanish and Italian special case of the full
+// algorithm. Only ASCII letters are considered.
+pub fn double_metaphone(s: &str) -> (String, Option<String>) {
+    let w: Vec<char> = s
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    let n = w.len();
+
+    let mut keys = Keys {
+        primary: String::new(),
+        alternate: String::new(),
+    };
+
+    let mut i = 0;
+    // Silent first letter of "gn", "kn", "pn", "wr" and "ps"
+    if starts_with_any(&w, 0, &["GN", "KN", "PN", "WR", "PS"]) {
+        i = 1;
+    }
+    // Initial X is pronounced S, as in "Xavier"
+    if char_at(&w, 0) == 'X' {
+        keys.push("S");
+        i = 1;
+    }
+
+    while i < n && !keys.done() {
+        let c = w[i];
+        let prev = if i > 0 { w[i - 1] } else { '\0' };
+        let next = char_at(&w, i + 1);
+
+        match c {
+            _ if is_vowel(c) => {
+                // Only an initial vowel is coded, and always as A
+                if i == 0 {
+                    keys.push("A");
+                }
+                i += 1;
+            }
+            'B' => {
+                keys.push("P");
+                i += if next == 'B' { 2 } else { 1 };
+            }
+            'C' => {
+                if starts_with_any(&w, i, &["CIA"]) {
+                    keys.push("X");
+                    i += 3;
+                } else if next == 'H' {
+                    if prev == 'S' || starts_with_any(&w, i + 2, &["R", "L"]) {
+                        keys.push("K");
+                    } else if i == 0 {
+                        keys.push_both("X", "K");
+                    } else {
+                        keys.push("X");
+                    }
+                    i += 2;
+                } else if next == 'C' && starts_with_any(&w, i + 2, &["I", "E", "H"]) {
+                    keys.push("KS");
+                    i += 3;
+                } else if matches!(next, 'I' | 'E' | 'Y') {
+                    keys.push("S");
+                    i += 2;
+                } else {
+                    keys.push("K");
+                    let doubled = matches!(next, 'C' | 'K' | 'Q' | 'G');
+                    i += if doubled { 2 } else { 1 };
+                }
+            }
+            'D' => {
+                if starts_with_any(&w, i, &["DGE", "DGI", "DGY"]) {
+                    keys.push("J");
+                    i += 3;
+                } else {
+                    keys.push("T");
+                    i += if matches!(next, 'T' | 'D') { 2 } else { 1 };
+                }
+            }
+            'G' => {
+                if next == 'H' {
+                    if i > 0 && !is_vowel(prev) {
+                        keys.push("K");
+                    } else if i == 0 {
+                        if char_at(&w, 2) == 'I' {
+                            keys.push("J");
+                        } else {
+                            keys.push("K");
+                        }
+                    } else if i > 2
+                        && prev == 'U'
+                        && matches!(w[i - 3], 'C' | 'G' | 'L' | 'R' | 'T')
+                    {
+                        // "laugh", "tough": GH sounds like F
+                        keys.push("F");
+                    }
+                    // Otherwise silent, as in "night" and "Hugh"
+                    i += 2;
+                } else if next == 'N' {
+                    // Silent G in "sign", "gnome"
+                    keys.push("N");
+                    i += 2;
+                } else if matches!(next, 'E' | 'I' | 'Y') {
+                    keys.push_both("J", "K");
+                    i += 2;
+                } else {
+                    keys.push("K");
+                    i += if next == 'G' { 2 } else { 1 };
+                }
+            }
+            'H' => {
+                // Only sounded at the start or between vowels
+                if (i == 0 || is_vowel(prev)) && is_vowel(next) {
+                    keys.push("H");
+                    i += 2;
+                } else {
+                    i += 1;
+                }
+            }
+            'J' => {
+                keys.push("J");
+                i += if next == 'J' { 2 } else { 1 };
+            }
+            'K' => {
+                keys.push("K");
+                i += if next == 'K' { 2 } else { 1 };
+            }
+            'P' => {
+                if next == 'H' {
+                    keys.push("F");
+                    i += 2;
+                } else {
+                    keys.push("P");
+                    i += if matches!(next, 'P' | 'B') { 2 } else { 1 };
+                }
+            }
+            'Q' => {
+                keys.push("K");
+                i += if next == 'Q' { 2 } else { 1 };
+            }
+            'S' => {
+                if next == 'H' {
+                    keys.push("X");
+                    i += 2;
+                } else if starts_with_any(&w, i, &["SIO", "SIA"]) {
+                    keys.push_both("X", "S");
+                    i += 3;
+                } else if i == 0 && starts_with_any(&w, i, &["SCH"]) && !is_vowel(char_at(&w, 3)) {
+                    // Germanic "Schmidt", "Schneider"
+                    keys.push_both("X", "S");
+                    i += 3;
+                } else if starts_with_any(&w, i, &["SCH"]) {
+                    keys.push("SK");
+                    i += 3;
+                } else if next == 'C' && matches!(char_at(&w, i + 2), 'I' | 'E' | 'Y') {
+                    keys.push("S");
+                    i += 3;
+                } else if i == 0 && matches!(next, 'M' | 'N' | 'L' | 'W') {
+                    // Germanic "Schmidt" is often anglicised as "Smith"
+                    keys.push_both("S", "X");
+                    i += 1;
+                } else {
+                    keys.push("S");
+                    i += if matches!(next, 'S' | 'Z') { 2 } else { 1 };
+                }
+            }
+            'T' => {
+                if starts_with_any(&w, i, &["TION", "TIA", "TCH"]) {
+                    keys.push("X");
+                    i += 3;
+                } else if next == 'H' && starts_with_any(&w, i + 2, &["OM", "AM"]) {
+                    // "Thomas", "Thompson"
+                    keys.push("T");
+                    i += 2;
+                } else if next == 'H' {
+                    keys.push_both("0", "T");
+                    i += 2;
+                } else {
+                    keys.push("T");
+                    i += if matches!(next, 'T' | 'D') { 2 } else { 1 };
+                }
+            }
+            'V' => {
+                keys.push("F");
+                i += if next == 'V' { 2 } else { 1 };
+            }
+            'W' => {
+                // Only an initial W before a vowel (or "wh") is sounded
+                if i == 0 && (is_vowel(next) || next == 'H') {
+                    keys.push_both("A", "F");
+                    i += if next == 'H' { 2 } else { 1 };
+                } else {
+                    i += 1;
+                }
+            }
+            'X' => {
+                keys.push("KS");
+                i += if matches!(next, 'C' | 'X') { 2 } else { 1 };
+            }
+            'Z' => {
+                if next == 'H' {
+                    keys.push("J");
+                    i += 2;
+                } else {
+                    keys.push("S");
+                    i += if next == 'Z' { 2 } else { 1 };
+                }
+            }
+            // F, L, M, N and R code as themselves
+            _ => {
+                let mut buf = [0; 4];
+                keys.push(c.encode_utf8(&mut buf));
+                i += if next == c { 2 } else { 1 };
+            }
+        }
+    }
+
+    keys.primary.truncate(METAPHONE_KEY_LEN);
+    keys.alternate.truncate(METAPHONE_KEY_LEN);
+    if keys.alternate == keys.primary {
+        (keys.primary, None)
+    } else {
+        (keys.primary, Some(keys.alternate))
+    }
+}
+
+// True if any Double Metaphone key of `a` equals any key of `b`. Strings
+// without letters (empty keys) never match.
+pub fn metaphone_matches(a: &str, b: &str) -> bool {
+    let (a_primary, a_alternate) = double_metaphone(a);
+    let (b_primary, b_alternate) = double_metaphone(b);
+    if a_primary.is_empty() || b_primary.is_empty() {
+        return false;
+    }
+    let a_keys = [Some(&a_primary), a_alternate.as_ref()];
+    let b_keys = [Some(&b_primary), b_alternate.as_ref()];
+    a_keys
+        .iter()
+        .flatten()
+        .any(|ka| b_keys.iter().flatten().any(|kb| ka == kb))
+}
+
+fn is_nysiis_vowel(c: char) -> bool {
+    matches!(c, 'A' | 'E' | 'I' | 'O' | 'U')
+}
+
+// Replaces `w[start..start + len]` with `with`.
+fn splice(w: &mut Vec<char>, start: usize, len: usize, with: &str) {
+    w.splice(start..start + len, with.chars());
+}
+
+// NYSIIS (New York State Identification and Intelligence System) code,
+// e.g. "MacDonald" -> "MCDANALD" and "Knight" -> "NAGT". Steps:
+// 1. rewrite the start: MAC -> MCC, KN -> N, K -> C, PH/PF -> FF, SCH -> SSS
+// 2. rewrite the end: EE/IE -> Y, DT/RT/RD/NT/ND -> D
+// 3. keep the first letter, then translate the rest: EV -> AF, other vowels
+//    -> A, Q -> G, Z -> S, M -> N, KN -> N, K -> C, SCH -> SS, PH -> F; an H
+//    not between vowels and a W after a vowel repeat the previous letter
+//    (as translated)
+// 4. skip any letter repeating the end of the code so far
+// 5. drop a trailing S, turn a trailing AY into Y, drop a trailing A
+// The code is not truncated (the original system kept six characters).
+// Only ASCII letters are considered; input without letters gives "".
+pub fn nysiis(s: &str) -> String {
+    let mut w: Vec<char> = s
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    if w.is_empty() {
+        return String::new();
+    }
+
+    if starts_with_any(&w, 0, &["MAC"]) {
+        splice(&mut w, 0, 3, "MCC");
+    } else if starts_with_any(&w, 0, &["KN"]) {
+        w.remove(0);
+    } else if w[0] == 'K' {
+        w[0] = 'C';
+    } else if starts_with_any(&w, 0, &["PH", "PF"]) {
+        splice(&mut w, 0, 2, "FF");
+    } else if starts_with_any(&w, 0, &["SCH"]) {
+        splice(&mut w, 0, 3, "SSS");
+    }
+
+    let n = w.len();
+    if n >= 2 {
+        let end = n - 2;
+        if starts_with_any(&w, end, &["EE", "IE"]) {
+            splice(&mut w, end, 2, "Y");
+        } else if starts_with_any(&w, end, &["DT", "RT", "RD", "NT", "ND"]) {
+            splice(&mut w, end, 2, "D");
+        }
+    }
+
+    let n = w.len();
+    let mut key = String::new();
+    key.push(w[0]);
+
+    let mut i = 1;
+    let mut buf = [0; 4];
+    while i < n {
+        let c = w[i];
+        let prev = w[i - 1];
+        let next = char_at(&w, i + 1);
+        let piece: &str = if c == 'E' && next == 'V' {
+            i += 1;
+            "AF"
+        } else if is_nysiis_vowel(c) {
+            "A"
+        } else if c == 'Q' {
+            "G"
+        } else if c == 'Z' {
+            "S"
+        } else if c == 'M' {
+            "N"
+        } else if c == 'K' {
+            if next == 'N' { "N" } else { "C" }
+        } else if c == 'S' && starts_with_any(&w, i + 1, &["CH"]) {
+            i += 2;
+            "SS"
+        } else if c == 'P' && next == 'H' {
+            i += 1;
+            "F"
+        } else if (c == 'H' && !(is_nysiis_vowel(prev) && is_nysiis_vowel(next)))
+            || (c == 'W' && is_nysiis_vowel(prev))
+        {
+            // Repeats the translated previous letter, which the code already
+            // ends with, so it adds nothing
+            i += 1;
+            continue;
+        } else {
+            c.encode_utf8(&mut buf)
+        };
+        if piece.chars().last() != key.chars().last() {
+            key.push_str(piece);
+        }
+        i += 1;
+    }
+
+    if key.len() > 1 && key.ends_with('S') {
+        key.pop();
+    }
+    if key.ends_with("AY") {
+        key.truncate(key.len() - 2);
+        key.push('Y');
+    }
+    if key.len() > 1 && key.ends_with('A') {
+        key.pop();
+    }
+    key
+}
+
+// True if both strings have the same, non-empty NYSIIS code.
+pub fn nysiis_matches(a: &str, b: &str) -> bool {
+    let code = nysiis(a);
+    !code.is_empty() && code == nysiis(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn soundex_reference_codes() {
+        assert_eq!(soundex("Robert"), "R163");
+        assert_eq!(soundex("Rupert"), "R163");
+        assert_eq!(soundex("Tymczak"), "T522");
+        assert_eq!(soundex("Pfister"), "P236");
+        assert_eq!(soundex("Lee"), "L000");
+        assert!(soundex_matches("Robert", "Rupert"));
+        assert!(!soundex_matches("Robert", "Rubin"));
+    }
+
+    #[test]
+    fn double_metaphone_common_cases() {
+        assert!(metaphone_matches("Smith", "Smyth"));
+        assert_eq!(double_metaphone("Smith").0, double_metaphone("Smyth").0);
+        assert_eq!(double_metaphone("Phillip").0, double_metaphone("Fillip").0);
+        assert_eq!(double_metaphone("Knight").0, double_metaphone("Night").0);
+        assert!(!metaphone_matches("Smith", "Jones"));
+    }
+
+    #[test]
+    fn nysiis_reference_codes() {
+        assert_eq!(nysiis("MacDonald"), "MCDANALD");
+        assert_eq!(nysiis("Knight"), "NAGT");
+        assert_eq!(nysiis("Phillipson"), "FALAPSAN");
+        assert!(nysiis_matches("Brown", "Braun"));
+        assert!(!nysiis_matches("", ""));
+    }
+}
@@ -0,0 +1,99 @@
+use alloc::collections::BTreeMap;
+use alloc::{vec, vec::Vec};
+
+use crate::lcs::longest_block;
+
+// Ratcliff-Obershelp ("gestalt pattern matching") similarity in [0.0, 1.0],
+// equivalent to Python's
+// `difflib.SequenceMatcher(None, a, b, autojunk=False).ratio()`: the longest
+// common block is found, then the pieces to its left and right are matched
+// the same way, and the ratio is 2 * matched / (len_a + len_b). There is no
+// junk heuristic, so for `b` of 200+ characters the results can differ from
+// difflib's default, which ignores characters making up over 1% of `b`.
+// Two empty strings are identical (1.0).
+pub fn ratcliff_obershelp(a: &str, b: &str) -> f64 {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+
+    let total = a_chars.len() + b_chars.len();
+    if total == 0 {
+        return 1.0;
+    }
+
+    // Explicit stack of (a range, b range) still to be matched, so long
+    // inputs can't overflow the call stack
+    let mut matched = 0;
+    let mut pending = vec![(0, a_chars.len(), 0, b_chars.len())];
+    while let Some((a_lo, a_hi, b_lo, b_hi)) = pending.pop() {
+        let (i, j, k) = longest_block(&a_chars[a_lo..a_hi], &b_chars[b_lo..b_hi]);
+        if k == 0 {
+            continue;
+        }
+        matched += k;
+        let (i, j) = (a_lo + i, b_lo + j);
+        pending.push((a_lo, i, b_lo, j));
+        pending.push((i + k, a_hi, j + k, b_hi));
+    }
+
+    2.0 * matched as f64 / total as f64
+}
+
+// difflib's `quick_ratio`: 2 * shared / (len_a + len_b), where `shared`
+// counts each character as many times as it occurs in both strings,
+// regardless of order. Every character matched by `ratcliff_obershelp` is
+// also counted here, so this is an upper bound on it that takes only a
+// pass over each string: if the quick ratio is under a threshold, the full
+// ratio is too and the matching can be skipped. Two empty strings are 1.0.
+pub fn quick_ratio(a: &str, b: &str) -> f64 {
+    let mut counts: BTreeMap<char, usize> = BTreeMap::new();
+    let mut a_len = 0;
+    for c in a.chars() {
+        *counts.entry(c).or_insert(0) += 1;
+        a_len += 1;
+    }
+
+    let mut shared = 0;
+    let mut b_len = 0;
+    for c in b.chars() {
+        b_len += 1;
+        if let Some(left) = counts.get_mut(&c).filter(|left| **left > 0) {
+            *left -= 1;
+            shared += 1;
+        }
+    }
+
+    let total = a_len + b_len;
+    if total == 0 {
+        1.0
+    } else {
+        2.0 * shared as f64 / total as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_difflib_ratio() {
+        // Values from Python's `SequenceMatcher(None, a, b, autojunk=False).ratio()`
+        assert_eq!(ratcliff_obershelp("abcd", "bcde"), 0.75);
+        assert_eq!(
+            ratcliff_obershelp("WIKIMEDIA", "WIKIMANIA"),
+            0.7777777777777778
+        );
+        assert_eq!(
+            ratcliff_obershelp("GESTALT PATTERN MATCHING", "GESTALT PRACTICE"),
+            0.6
+        );
+        assert_eq!(
+            ratcliff_obershelp(
+                "private Thread currentThread;",
+                "private volatile Thread currentThread;"
+            ),
+            0.8656716417910447
+        );
+        assert_eq!(ratcliff_obershelp("", ""), 1.0);
+        assert_eq!(ratcliff_obershelp("", "abc"), 0.0);
+    }
+}
@@ -0,0 +1,91 @@
+use core::cmp::Ordering;
+
+use crate::jaro::{jaro, jaro_winkler};
+use crate::levenshtein::{normalized_partial_similarity, normalized_similarity};
+
+// A similarity score in [0.0, 1.0] that is never NaN, so it can be compared
+// with `==`, sorted and used as a map key. Ordering follows `f64::total_cmp`;
+// -0.0 is stored as 0.0 so the two compare equal.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Score(f64);
+
+impl Score {
+    // Some(score) if `value` is in [0.0, 1.0], None otherwise (including NaN).
+    pub fn new(value: f64) -> Option<Score> {
+        if (0.0..=1.0).contains(&value) {
+            // Adding 0.0 turns -0.0 into 0.0
+            Some(Score(value + 0.0))
+        } else {
+            None
+        }
+    }
+
+    // For scores that are in range by construction; clamps away any rounding
+    // drift at the ends.
+    fn from_unit(value: f64) -> Score {
+        Score(value.clamp(0.0, 1.0) + 0.0)
+    }
+
+    pub fn get(self) -> f64 {
+        self.0
+    }
+
+    // The score as a rounded percentage in 0..=100.
+    pub fn as_percent(&self) -> u8 {
+        to_percent(self.0)
+    }
+}
+
+impl PartialEq for Score {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Score {}
+
+impl PartialOrd for Score {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Score {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl From<Score> for f64 {
+    fn from(score: Score) -> f64 {
+        score.0
+    }
+}
+
+// Rounds a similarity in [0.0, 1.0] to the nearest percentage in 0..=100.
+// Values outside the range are clamped first; NaN gives 0.
+pub(crate) fn to_percent(value: f64) -> u8 {
+    // Non-negative after clamping, so adding 0.5 and truncating rounds
+    // without std's `round`
+    (value.clamp(0.0, 1.0) * 100.0 + 0.5) as u8
+}
+
+// `normalized_similarity` as a `Score`.
+pub fn similarity_score(a: &str, b: &str) -> Score {
+    Score::from_unit(normalized_similarity(a, b))
+}
+
+// `normalized_partial_similarity` as a `Score`.
+pub fn partial_similarity_score(a: &str, b: &str) -> Score {
+    Score::from_unit(normalized_partial_similarity(a, b))
+}
+
+// `jaro` as a `Score`.
+pub fn jaro_score(a: &str, b: &str) -> Score {
+    Score::from_unit(jaro(a, b))
+}
+
+// `jaro_winkler` as a `Score`.
+pub fn jaro_winkler_score(a: &str, b: &str) -> Score {
+    Score::from_unit(jaro_winkler(a, b))
+}
@@ -0,0 +1,146 @@
+use alloc::collections::BinaryHeap;
+use alloc::{vec, vec::Vec};
+
+use crate::levenshtein::{distance, distance_within};
+
+// Closest candidate to `query` and its distance, or None for an empty list.
+// Each candidate after the first is checked with `distance_within` bounded
+// just below the best distance so far, so candidates that cannot win are
+// abandoned early. Ties go to the first candidate in input order.
+pub fn best_match<'a>(query: &str, candidates: &'a [&'a str]) -> Option<(&'a str, usize)> {
+    let mut best: Option<(&'a str, usize)> = None;
+
+    for &candidate in candidates {
+        let d = match best {
+            None => Some(distance(query, candidate)),
+            Some((_, 0)) => break,
+            Some((_, best_dist)) => distance_within(query, candidate, best_dist - 1),
+        };
+        if let Some(d) = d {
+            best = Some((candidate, d));
+        }
+    }
+
+    best
+}
+
+// The `k` closest candidates to `query`, sorted ascending by distance and
+// then by input order. A max-heap holds the best `k` seen so far; once it is
+// full, its worst distance becomes the bound for later candidates, so those
+// whose length difference alone reaches it are skipped without running the
+// DP. If `k` exceeds the number of candidates, all of them are returned.
+pub fn top_k<'a>(query: &str, candidates: &'a [&str], k: usize) -> Vec<(&'a str, usize)> {
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let query_len = query.chars().count();
+    // (distance, index) so equal distances order by input position
+    // Never more than the candidates, however large `k` is
+    let capacity = k.min(candidates.len()).saturating_add(1);
+    let mut heap: BinaryHeap<(usize, usize)> = BinaryHeap::with_capacity(capacity);
+
+    for (idx, &candidate) in candidates.iter().enumerate() {
+        if heap.len() < k {
+            heap.push((distance(query, candidate), idx));
+            continue;
+        }
+
+        let worst = heap.peek().map_or(0, |&(d, _)| d);
+        if worst == 0 {
+            break;
+        }
+        if query_len.abs_diff(candidate.chars().count()) >= worst {
+            continue;
+        }
+        if let Some(d) = distance_within(query, candidate, worst - 1) {
+            heap.pop();
+            heap.push((d, idx));
+        }
+    }
+
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|(d, idx)| (candidates[idx], d))
+        .collect()
+}
+
+// Every candidate within `max` edits of `query`, with its distance, in input
+// order. Candidates whose length difference alone exceeds `max` are skipped,
+// and the rest go through `distance_within`, which abandons the DP as soon
+// as `max` is out of reach.
+pub fn matches_within<'a>(
+    query: &str,
+    candidates: &'a [&str],
+    max: usize,
+) -> Vec<(&'a str, usize)> {
+    let query_len = query.chars().count();
+    candidates
+        .iter()
+        .filter(|candidate| query_len.abs_diff(candidate.chars().count()) <= max)
+        .filter_map(|&candidate| distance_within(query, candidate, max).map(|d| (candidate, d)))
+        .collect()
+}
+
+// Root of `i` in a union-find forest, halving paths along the way.
+fn find_root(parent: &mut [usize], mut i: usize) -> usize {
+    while parent[i] != i {
+        parent[i] = parent[parent[i]];
+        i = parent[i];
+    }
+    i
+}
+
+// Groups near-duplicate strings by single linkage: two items land in the
+// same cluster if they are within `max_dist` edits of each other, directly
+// or through a chain of such items. Returns clusters of indices into
+// `items`, each sorted ascending and ordered by their first index; items
+// with no near neighbor form clusters of one. Every pair is considered, but
+// pairs already in one cluster or whose length difference exceeds
+// `max_dist` are skipped, and the rest use `distance_within`.
+pub fn cluster(items: &[&str], max_dist: usize) -> Vec<Vec<usize>> {
+    let lengths: Vec<usize> = items.iter().map(|s| s.chars().count()).collect();
+    let mut parent: Vec<usize> = (0..items.len()).collect();
+
+    for i in 0..items.len() {
+        for j in i + 1..items.len() {
+            if lengths[i].abs_diff(lengths[j]) > max_dist {
+                continue;
+            }
+            let (ri, rj) = (find_root(&mut parent, i), find_root(&mut parent, j));
+            if ri != rj && distance_within(items[i], items[j], max_dist).is_some() {
+                parent[ri.max(rj)] = ri.min(rj);
+            }
+        }
+    }
+
+    let mut clusters: Vec<Vec<usize>> = Vec::new();
+    // Position in `clusters` of the cluster rooted at each index
+    let mut slot: Vec<Option<usize>> = vec![None; items.len()];
+    for i in 0..items.len() {
+        let root = find_root(&mut parent, i);
+        match slot[root] {
+            Some(c) => clusters[c].push(i),
+            None => {
+                slot[root] = Some(clusters.len());
+                clusters.push(vec![i]);
+            }
+        }
+    }
+
+    clusters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn top_k_larger_than_candidates_returns_all() {
+        let candidates = ["xyz", "abd", "abc"];
+        let expected = vec![("abc", 0), ("abd", 1), ("xyz", 3)];
+        assert_eq!(top_k("abc", &candidates, 1 << 50), expected);
+        assert_eq!(top_k("abc", &candidates, usize::MAX), expected);
+        assert_eq!(top_k("abc", &candidates, 2), expected[..2]);
+    }
+}
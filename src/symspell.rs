@@ -0,0 +1,110 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use std::collections::{HashMap, HashSet};
+
+use crate::levenshtein::distance;
+
+// Every string reachable from `term` by deleting at most `max` characters,
+// including `term` itself.
+fn deletions(term: &str, max: usize) -> HashSet<String> {
+    let mut seen: HashSet<String> = HashSet::from([String::from(term)]);
+    let mut frontier: Vec<String> = Vec::from([String::from(term)]);
+
+    for _ in 0..max {
+        let mut next: Vec<String> = Vec::new();
+        for word in &frontier {
+            for (i, c) in word.char_indices() {
+                let mut shorter = String::with_capacity(word.len());
+                shorter.push_str(&word[..i]);
+                shorter.push_str(&word[i + c.len_utf8()..]);
+                if seen.insert(shorter.clone()) {
+                    next.push(shorter);
+                }
+            }
+        }
+        frontier = next;
+    }
+
+    seen
+}
+
+// Symmetric-delete spelling index (SymSpell). Each added term is stored under
+// every variant obtained by deleting up to `max_edit` of its characters. Two
+// strings within `k` edits of each other always share such a variant, so a
+// lookup only generates the query's own deletions and checks the terms filed
+// under them with the real `distance`, instead of scanning the dictionary.
+// Memory grows quickly with `max_edit`; 1 or 2 is typical.
+#[derive(Debug, Clone, Default)]
+pub struct SymSpell {
+    max_edit: usize,
+    terms: Vec<String>,
+    // Deletion variant -> indices into `terms`
+    deletes: HashMap<String, Vec<usize>>,
+}
+
+impl SymSpell {
+    pub fn new(max_edit: usize) -> Self {
+        SymSpell {
+            max_edit,
+            ..SymSpell::default()
+        }
+    }
+
+    // Largest edit distance lookups can search.
+    pub fn max_edit(&self) -> usize {
+        self.max_edit
+    }
+
+    // Number of distinct terms added.
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    // Adds `term` to the dictionary. Terms already present are ignored.
+    pub fn add(&mut self, term: &str) {
+        let exists = self
+            .deletes
+            .get(term)
+            .is_some_and(|ids| ids.iter().any(|&id| self.terms[id] == term));
+        if exists {
+            return;
+        }
+
+        let id = self.terms.len();
+        self.terms.push(String::from(term));
+        for variant in deletions(term, self.max_edit) {
+            self.deletes.entry(variant).or_default().push(id);
+        }
+    }
+
+    // All dictionary terms within `max` edits of `term`, with their
+    // distances, sorted by distance and then alphabetically. `max` is capped
+    // at the index's `max_edit`, since larger distances weren't indexed.
+    pub fn lookup(&self, term: &str, max: usize) -> Vec<(String, usize)> {
+        let max = max.min(self.max_edit);
+
+        let mut checked: HashSet<usize> = HashSet::new();
+        let mut found: Vec<(String, usize)> = Vec::new();
+        for variant in deletions(term, max) {
+            let Some(ids) = self.deletes.get(&variant) else {
+                continue;
+            };
+            for &id in ids {
+                if !checked.insert(id) {
+                    continue;
+                }
+                let d = distance(term, &self.terms[id]);
+                if d <= max {
+                    found.push((self.terms[id].clone(), d));
+                }
+            }
+        }
+
+        found.sort_unstable_by(|x, y| x.1.cmp(&y.1).then_with(|| x.0.cmp(&y.0)));
+        found
+    }
+}
@@ -0,0 +1,65 @@
+use alloc::string::String;
+
+// SplitMix64: tiny, fast, and well-distributed for any seed (including 0).
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in [0.0, 1.0).
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    // Uniform in 0..n, for small n.
+    fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    fn letter(&mut self) -> char {
+        (b'a' + self.below(26) as u8) as char
+    }
+}
+
+// Reproducible benchmark/test input (requires the `testutil` feature): a
+// random lowercase ASCII string of `len` characters and a mutated copy. Each
+// position of the base is edited with probability `edit_rate` (clamped to
+// [0.0, 1.0]), the edit being a substitution, insertion or deletion with
+// equal odds, so the distance between the two is roughly
+// `edit_rate * len`. The same `seed` always gives the same pair.
+pub fn random_pair(seed: u64, len: usize, edit_rate: f64) -> (String, String) {
+    let mut rng = SplitMix64(seed);
+    let edit_rate = edit_rate.clamp(0.0, 1.0);
+
+    let base: String = (0..len).map(|_| rng.letter()).collect();
+    let mut mutated = String::with_capacity(len + len / 4);
+    for c in base.chars() {
+        if rng.next_f64() >= edit_rate {
+            mutated.push(c);
+            continue;
+        }
+        match rng.below(3) {
+            0 => mutated.push(rng.letter()),
+            1 => {
+                mutated.push(c);
+                mutated.push(rng.letter());
+            }
+            _ => {}
+        }
+    }
+
+    (base, mutated)
+}
+
+// The seeded fuzz corpus shared by the crate's tests (requires the
+// `testutil` feature): 400 `random_pair`s with lengths cycling through
+// 0..40 and edit rates through 0.0, 0.2, ..., 0.8.
+pub fn random_corpus() -> impl Iterator<Item = (String, String)> {
+    (0..400u64).map(|seed| random_pair(seed, (seed % 40) as usize, (seed % 5) as f64 * 0.2))
+}
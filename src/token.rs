@@ -0,0 +1,163 @@
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::{vec, vec::Vec};
+
+use crate::levenshtein::{normalized_partial_similarity, normalized_similarity};
+use crate::score::to_percent;
+
+// Whitespace-separated tokens of `s`, sorted and rejoined with single spaces.
+fn sorted_tokens(s: &str) -> String {
+    let mut tokens: Vec<&str> = s.split_whitespace().collect();
+    tokens.sort_unstable();
+    tokens.join(" ")
+}
+
+// Word-order-insensitive similarity in [0.0, 1.0] (multiply by 100 for
+// fuzzywuzzy's scale): both strings are split on whitespace, their tokens
+// sorted and rejoined with single spaces, and the results compared with
+// `normalized_similarity`. "new york city" vs "city new york" is 1.0.
+pub fn token_sort_ratio(a: &str, b: &str) -> f64 {
+    normalized_similarity(sorted_tokens(a), sorted_tokens(b))
+}
+
+// `token_sort_ratio` comparing the sorted strings with
+// `normalized_partial_similarity`, so the shorter one only has to match the
+// best window of the longer (fuzzywuzzy's `partial_token_sort_ratio`).
+pub fn partial_token_sort_ratio(a: &str, b: &str) -> f64 {
+    normalized_partial_similarity(sorted_tokens(a), sorted_tokens(b))
+}
+
+// Token-set similarity in [0.0, 1.0], following fuzzywuzzy's formula so one
+// string being a superset of the other's words still scores highly. With
+// `sect` the sorted intersection of the two token sets and `rest_a`/`rest_b`
+// the sorted tokens only in `a`/`b` (each joined with single spaces),
+//
+//     t0 = sect
+//     t1 = sect + " " + rest_a
+//     t2 = sect + " " + rest_b
+//
+// (trimmed when a part is empty) the result is the best `normalized_similarity`
+// of (t0, t1), (t0, t2) and (t1, t2). Duplicate tokens are ignored. If either
+// string has no tokens the result is 1.0 when both are empty and 0.0 otherwise.
+pub fn token_set_ratio(a: &str, b: &str) -> f64 {
+    token_set_by(a, b, |x, y| normalized_similarity(x, y))
+}
+
+// `token_set_ratio` scoring the three pairs with
+// `normalized_partial_similarity` (fuzzywuzzy's `partial_token_set_ratio`).
+// Any shared token makes `sect` a prefix of both combined strings, so this is
+// 1.0 whenever the two strings have a word in common.
+pub fn partial_token_set_ratio(a: &str, b: &str) -> f64 {
+    token_set_by(a, b, |x, y| normalized_partial_similarity(x, y))
+}
+
+// The token-set construction shared by `token_set_ratio` and
+// `partial_token_set_ratio`, with `score` comparing the joined strings.
+fn token_set_by(a: &str, b: &str, score: fn(&str, &str) -> f64) -> f64 {
+    let a_tokens: BTreeSet<&str> = a.split_whitespace().collect();
+    let b_tokens: BTreeSet<&str> = b.split_whitespace().collect();
+
+    if a_tokens.is_empty() || b_tokens.is_empty() {
+        return if a_tokens.is_empty() && b_tokens.is_empty() {
+            1.0
+        } else {
+            0.0
+        };
+    }
+
+    let join = |tokens: Vec<&str>| tokens.join(" ");
+    let sect = join(a_tokens.intersection(&b_tokens).copied().collect());
+    let rest_a = join(a_tokens.difference(&b_tokens).copied().collect());
+    let rest_b = join(b_tokens.difference(&a_tokens).copied().collect());
+
+    let combine = |rest: &str| {
+        let mut combined = sect.clone();
+        if !combined.is_empty() && !rest.is_empty() {
+            combined.push(' ');
+        }
+        combined.push_str(rest);
+        combined
+    };
+    let t1 = combine(&rest_a);
+    let t2 = combine(&rest_b);
+
+    score(&sect, &t1)
+        .max(score(&sect, &t2))
+        .max(score(&t1, &t2))
+}
+
+// Word-level edit distance: both strings are split on whitespace and the
+// Levenshtein DP runs over the tokens. Inserting or deleting a word costs 1
+// and replacing `wa` with `wb` costs `word_cost(wa, wb)`, consulted for every
+// aligned pair, so it should return 0 for equal words. Since a replacement
+// can always be done as a deletion plus an insertion, any cost above 2 acts
+// as 2. Passing char-level `distance` makes a one-letter typo cost 1 while
+// an unrelated word costs 2; `|x, y| (x != y) as usize` gives plain
+// token-level Levenshtein.
+pub fn token_distance<F: Fn(&str, &str) -> usize>(a: &str, b: &str, word_cost: F) -> usize {
+    let a_tokens: Vec<&str> = a.split_whitespace().collect();
+    let b_tokens: Vec<&str> = b.split_whitespace().collect();
+
+    let m = b_tokens.len();
+    let mut prev: Vec<usize> = (0..=m).collect();
+    let mut curr: Vec<usize> = vec![0; m + 1];
+
+    for (i, wa) in a_tokens.iter().enumerate() {
+        curr[0] = i + 1;
+        for j in 0..m {
+            let deletion = prev[j + 1] + 1;
+            let insertion = curr[j] + 1;
+            let substitution = prev[j] + word_cost(wa, b_tokens[j]);
+            curr[j + 1] = deletion.min(insertion).min(substitution);
+        }
+        core::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[m]
+}
+
+// Length ratio above which `weighted_ratio` also considers partial matches,
+// and above which it trusts them less.
+const PARTIAL_MIN_LEN_RATIO: f64 = 1.5;
+const PARTIAL_LONG_LEN_RATIO: f64 = 8.0;
+// `weighted_ratio` scaling: token ratios count for 0.95 of a full match, and
+// partial ratios for 0.9 (0.6 once one string is over 8 times the other).
+const TOKEN_SCALE: f64 = 0.95;
+const PARTIAL_SCALE: f64 = 0.9;
+const LONG_PARTIAL_SCALE: f64 = 0.6;
+
+// fuzzywuzzy-style `WRatio` in 0..=100: the best of several scaled scores,
+// a reasonable default when it is unclear which ratio fits the data. The
+// plain `normalized_similarity` counts in full. When the lengths are within
+// a factor of 1.5, `token_sort_ratio` and `token_set_ratio` are added,
+// scaled by 0.95. Otherwise the shorter string is more likely a fragment of
+// the longer, so `partial_ratio` is added scaled by 0.9, as are
+// `partial_token_sort_ratio` and `partial_token_set_ratio` scaled by
+// 0.95 * 0.9; past a factor of 8 the 0.9 drops to 0.6. Unlike fuzzywuzzy no
+// case folding or punctuation stripping is done. Two empty strings score
+// 100, and one empty string against a non-empty one 0.
+pub fn weighted_ratio(a: &str, b: &str) -> u8 {
+    let (a_len, b_len) = (a.chars().count(), b.chars().count());
+    if a_len == 0 || b_len == 0 {
+        return if a_len == b_len { 100 } else { 0 };
+    }
+
+    let len_ratio = a_len.max(b_len) as f64 / a_len.min(b_len) as f64;
+    let mut best = normalized_similarity(a, b);
+
+    if len_ratio < PARTIAL_MIN_LEN_RATIO {
+        let token = token_sort_ratio(a, b).max(token_set_ratio(a, b));
+        best = best.max(token * TOKEN_SCALE);
+    } else {
+        let partial_scale = if len_ratio > PARTIAL_LONG_LEN_RATIO {
+            LONG_PARTIAL_SCALE
+        } else {
+            PARTIAL_SCALE
+        };
+        let partial = normalized_partial_similarity(a, b) * partial_scale;
+        let token = partial_token_sort_ratio(a, b).max(partial_token_set_ratio(a, b));
+        best = best.max(partial).max(token * TOKEN_SCALE * partial_scale);
+    }
+
+    to_percent(best)
+}
@@ -0,0 +1,87 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthChar;
+
+use crate::levenshtein::{Edit, distance_slice, edit_script};
+
+// Levenshtein distance over extended grapheme clusters instead of `char`s,
+// so "é" written as 'e' + U+0301 is one unit, as are emoji ZWJ sequences and
+// flags. Only available with the opt-in `unicode` feature, which pulls in the
+// `unicode-segmentation` crate.
+pub fn distance_graphemes(a: &str, b: &str) -> usize {
+    let a_graphemes: Vec<&str> = a.graphemes(true).collect();
+    let b_graphemes: Vec<&str> = b.graphemes(true).collect();
+    distance_slice(&a_graphemes, &b_graphemes)
+}
+
+// Levenshtein distance after normalizing both strings to NFC, so canonically
+// equivalent strings (precomposed vs decomposed accents) compare as 0. Kept
+// separate from `distance` because normalization has a real cost.
+pub fn distance_normalized_nfc(a: &str, b: &str) -> usize {
+    let a_chars: Vec<char> = a.nfc().collect();
+    let b_chars: Vec<char> = b.nfc().collect();
+    distance_slice(&a_chars, &b_chars)
+}
+
+// Levenshtein distance ignoring diacritics: both strings are NFD-decomposed
+// and combining marks are dropped, so "café" vs "cafe" and "Åse" vs "Ase" are
+// 0. This only strips combining marks; it does not transliterate non-Latin
+// scripts, and letters without a decomposition (e.g. 'ø', 'ß') are kept.
+pub fn distance_ascii_fold(a: &str, b: &str) -> usize {
+    let a_chars: Vec<char> = a.nfd().filter(|&c| !is_combining_mark(c)).collect();
+    let b_chars: Vec<char> = b.nfd().filter(|&c| !is_combining_mark(c)).collect();
+    distance_slice(&a_chars, &b_chars)
+}
+
+// `align` for display in a monospace terminal. Each aligned pair becomes a
+// column as wide as the wider of its two characters (per `unicode-width`,
+// with control characters counted as zero): a gap is repeated to fill the
+// column, so a deleted or inserted full-width character gets two gap
+// chars, and a narrow character facing a wide one is padded with spaces.
+// The two results therefore have equal display width rather than equal
+// character counts.
+pub fn align_display_width(a: &str, b: &str, gap: char) -> (String, String) {
+    let mut top = String::with_capacity(a.len());
+    let mut bottom = String::with_capacity(b.len());
+
+    let push = |out: &mut String, c: Option<char>, width: usize| match c {
+        Some(c) => {
+            out.push(c);
+            let used = c.width().unwrap_or(0);
+            out.extend(core::iter::repeat_n(' ', width.saturating_sub(used)));
+        }
+        None => out.extend(core::iter::repeat_n(gap, width)),
+    };
+
+    for edit in edit_script(a, b) {
+        let (x, y) = match edit {
+            Edit::Match(c) => (Some(c), Some(c)),
+            Edit::Substitute(x, y) => (Some(x), Some(y)),
+            Edit::Insert(c) => (None, Some(c)),
+            Edit::Delete(c) => (Some(c), None),
+        };
+        let width = |c: Option<char>| c.and_then(|c| c.width()).unwrap_or(0);
+        let column = width(x).max(width(y));
+        push(&mut top, x, column);
+        push(&mut bottom, y, column);
+    }
+
+    (top, bottom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_fold_drops_combining_marks() {
+        assert_eq!(distance_ascii_fold("naïve", "naive"), 0);
+        assert_eq!(distance_ascii_fold("Åse", "Ase"), 0);
+        assert_eq!(distance_ascii_fold("café", "cafe\u{301}"), 0);
+        // No decomposition, so nothing to strip
+        assert_eq!(distance_ascii_fold("ø", "o"), 1);
+    }
+}